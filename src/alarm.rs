@@ -0,0 +1,120 @@
+///Built-in alarm effects for the buzzer
+///
+/// A single steady tone is easy to tune out, so these sweep or chop the frequency over time instead.
+/// Rather than stringing together blocking [crate::tone_duration] calls, the effect is advanced from
+/// the [crate::timer] tick, which reprograms Timer2 (and with it OCR2A) while the main loop keeps sampling.
+///
+/// Every effect is built around a base frequency, which should be wherever the buzzer is loudest
+/// (see [crate::calibration] and [set_base_frequency]).
+use crate::{no_tone, tone_volume};
+use avr_device::interrupt::Mutex;
+use core::cell::Cell;
+
+/// How often (in ms) the tick reprograms Timer2. Every tick would be smoother, but the math
+/// in [tone_volume] isn't free and this runs in interrupt context
+const UPDATE_INTERVAL_MS: u32 = 4;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Effect {
+    /// Slow rise and fall from half the base frequency up to 1.5x of it, like an air raid siren
+    Siren,
+    /// Fast wobble around the base frequency
+    Warble,
+    /// Short rising chirps with gaps between them
+    Chirp,
+    /// Alternates between the base frequency and a major third below it, like a European police siren
+    TwoTone,
+}
+
+impl Effect {
+    /// Frequency the effect should be at `t` ms after it started, [None] for the silent parts
+    fn frequency(self, t: u32, base: u16) -> Option<u16> {
+        match self {
+            Effect::Siren => Some(sweep(t, 2000, base / 2, base / 2 * 3)),
+            Effect::Warble => Some(sweep(t, 120, base / 10 * 9, base / 10 * 11)),
+            Effect::Chirp => {
+                //Rises from 3/4 to 3/2 of the base frequency over the first 60ms
+                let t = t % 200;
+                if t < 60 {
+                    Some(base / 4 * 3 + (base as u32 * 3 / 4 * t / 60) as u16)
+                } else {
+                    None
+                }
+            }
+            Effect::TwoTone => Some(if (t / 500) % 2 == 0 {
+                base
+            } else {
+                base / 5 * 4
+            }),
+        }
+    }
+}
+
+/// Triangle wave going from `low` to `high` and back again over `period` ms
+fn sweep(t: u32, period: u32, low: u16, high: u16) -> u16 {
+    let half = period / 2;
+    let phase = t % period;
+    let pos = if phase < half { phase } else { period - phase };
+    low + ((high - low) as u32 * pos / half) as u16
+}
+
+#[derive(Clone, Copy)]
+struct Playing {
+    effect: Effect,
+    volume: u8,
+    started: u32,
+}
+
+static PLAYING: Mutex<Cell<Option<Playing>>> = Mutex::new(Cell::new(None));
+static BASE_FREQUENCY: Mutex<Cell<u16>> =
+    Mutex::new(Cell::new(crate::calibration::DEFAULT_FREQUENCY));
+
+pub fn set_base_frequency(frequency: u16) {
+    avr_device::interrupt::free(|cs| BASE_FREQUENCY.borrow(cs).set(frequency));
+}
+
+/// Starts playing `effect` (see [crate::tone_volume] for `volume`). Calling this again with the
+/// same effect only changes the volume, so the sweep carries on where it was
+pub fn play(effect: Effect, volume: u8) {
+    avr_device::interrupt::free(|cs| {
+        let playing = PLAYING.borrow(cs);
+        let started = match playing.get() {
+            Some(current) if current.effect == effect => current.started,
+            _ => crate::timer::millis(),
+        };
+        playing.set(Some(Playing {
+            effect,
+            volume,
+            started,
+        }));
+    });
+}
+
+pub fn stop(tc2: &avr_device::atmega328p::TC2) {
+    avr_device::interrupt::free(|cs| {
+        PLAYING.borrow(cs).set(None);
+        no_tone(tc2);
+    });
+}
+
+/// Called from the [crate::timer] ISR with the current time
+pub fn tick(now: u32) {
+    if now % UPDATE_INTERVAL_MS != 0 {
+        return;
+    }
+
+    avr_device::interrupt::free(|cs| {
+        if let Some(playing) = PLAYING.borrow(cs).get() {
+            //SAFETY: While an effect is playing, main leaves TC2 alone and goes through [play]/[stop]
+            let dp = unsafe { avr_device::atmega328p::Peripherals::steal() };
+            let base = BASE_FREQUENCY.borrow(cs).get();
+            match playing
+                .effect
+                .frequency(now.wrapping_sub(playing.started), base)
+            {
+                Some(frequency) => tone_volume(&dp.TC2, frequency, playing.volume),
+                None => no_tone(&dp.TC2),
+            }
+        }
+    });
+}
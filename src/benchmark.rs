@@ -0,0 +1,155 @@
+///Measures how much [SSD1306Display::set_batching] actually buys on the real bus
+///
+/// Send 'b' over serial while it boots to run it. The same drawing gets done with batching off and then on:
+/// clearing the screen, a screen full of text as wide as the panel, and a scaled up value like the readout on
+/// a screen of its own. Each mode reports how long it took, and how many transactions and bytes went over the
+/// wire, to serial.
+use crate::display::{DisplayError, SSD1306Display};
+use crate::timer;
+use embedded_hal::i2c::{ErrorType, I2c as BaseI2c, Operation};
+use ufmt::uWrite;
+use unwrap_infallible::UnwrapInfallible as _;
+
+/// Times the drawing gets repeated per mode, once is over too quickly for a millisecond clock
+const REPS: u8 = 10;
+
+/// Passes everything on to the real bus, counting along the way
+struct CountingBus<'a, I> {
+    wire: &'a mut I,
+    transactions: u32,
+    bytes: u32,
+}
+
+impl<I: ErrorType> ErrorType for CountingBus<'_, I> {
+    type Error = I::Error;
+}
+
+impl<I: BaseI2c> BaseI2c for CountingBus<'_, I> {
+    fn transaction(
+        &mut self,
+        address: u8,
+        operations: &mut [Operation<'_>],
+    ) -> Result<(), Self::Error> {
+        self.transactions += 1;
+        //The address byte goes out every time as well
+        self.bytes += 1;
+        for operation in operations.iter() {
+            self.bytes += match operation {
+                Operation::Write(bytes) => bytes.len() as u32,
+                Operation::Read(buffer) => buffer.len() as u32,
+            };
+        }
+        self.wire.transaction(address, operations)
+    }
+}
+
+struct Stats {
+    ms: u32,
+    transactions: u32,
+    bytes: u32,
+}
+
+/// Filler for the text rows, cut down to what fits on a line of the panel
+const TEXT: &str = "The quick brown fox jumps over the lazy dog";
+
+/// The scaled up value, drawn as big as it fits up to the readout's scale
+const VALUE: &str = "88.8";
+const VALUE_SCALE: u8 = 4;
+
+/// The longest start of [TEXT] that fits across the panel in the current font, so no row wraps or clips
+fn line(display: &SSD1306Display) -> &'static str {
+    let font = display.font();
+    let mut width = 0;
+    let end = TEXT
+        .char_indices()
+        .find(|&(_, ch)| {
+            width += font.char_width(ch) as u16;
+            width > display.width() as u16
+        })
+        .map_or(TEXT.len(), |(index, _)| index);
+    &TEXT[..end]
+}
+
+/// [VALUE_SCALE], or less if the value wouldn't fit on the panel that big
+fn value_scale(display: &SSD1306Display) -> u8 {
+    let font = display.font();
+    let across = display.width() as u16 / font.text_width(VALUE).max(1);
+    let down = display.height() / 8 / font.pages().max(1);
+    across.min(down as u16).clamp(1, VALUE_SCALE as u16) as u8
+}
+
+/// Draws one round of the benchmark. Text and the scaled value each start from a blank screen, so nothing
+/// is skipped as already there and neither one draws over the other
+fn draw<I: BaseI2c>(
+    display: &mut SSD1306Display,
+    wire: &mut I,
+    line: &str,
+    scale: u8,
+) -> Result<(), DisplayError<I::Error>> {
+    display.clear(wire)?;
+    for row in 0..display.height() / 8 {
+        display.set_cursor(wire, 0, row)?;
+        display
+            .write_str(wire, line)
+            .map_err(|partial| partial.error)?;
+    }
+    display.clear(wire)?;
+    display.set_cursor(wire, 0, 0)?;
+    display
+        .write_str_scaled(wire, VALUE, scale)
+        .map_err(|partial| partial.error)?;
+    Ok(())
+}
+
+fn measure<I: BaseI2c>(
+    display: &mut SSD1306Display,
+    wire: &mut I,
+    batching: bool,
+) -> Result<Stats, DisplayError<I::Error>> {
+    display.set_batching(batching);
+    let mut bus = CountingBus {
+        wire,
+        transactions: 0,
+        bytes: 0,
+    };
+    let line = line(display);
+    let scale = value_scale(display);
+    let start = timer::millis();
+    for _ in 0..REPS {
+        draw(display, &mut bus, line, scale)?;
+    }
+    Ok(Stats {
+        ms: timer::millis().wrapping_sub(start),
+        transactions: bus.transactions,
+        bytes: bus.bytes,
+    })
+}
+
+/// Runs the benchmark and logs the results to `serial`. Batching is put back the way it was, the screen is
+/// left with the scaled value on it
+pub fn run<I: BaseI2c, W: uWrite<Error = core::convert::Infallible>>(
+    display: &mut SSD1306Display,
+    wire: &mut I,
+    serial: &mut W,
+) -> Result<(), DisplayError<I::Error>> {
+    let batching = display.is_batching();
+    let results = measure(display, wire, false)
+        .and_then(|unbatched| Ok((unbatched, measure(display, wire, true)?)));
+    display.set_batching(batching);
+    let (unbatched, batched) = results?;
+
+    for (mode, stats) in [("Unbatched", &unbatched), ("Batched", &batched)] {
+        ufmt::uwriteln!(
+            serial,
+            "{}: {}ms, {} transactions, {} bytes\r",
+            mode,
+            stats.ms,
+            stats.transactions,
+            stats.bytes
+        )
+        .unwrap_infallible();
+    }
+    let saved = unbatched.ms.saturating_sub(batched.ms) * 100 / unbatched.ms.max(1);
+    ufmt::uwriteln!(serial, "Batching saves {}% of the time\r", saved).unwrap_infallible();
+    Ok(())
+}
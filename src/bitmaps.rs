@@ -0,0 +1,74 @@
+//Generated by tools/img2bitmap.py, do not edit by hand
+//Regenerate with: python3 tools/img2bitmap.py assets/logo.pbm assets/icons/alarm.pbm assets/icons/error.pbm assets/icons/mute.pbm > src/bitmaps.rs
+use crate::display::Bitmap;
+
+//logo.pbm, 128x32
+#[rustfmt::skip]
+#[link_section = ".progmem.data"]
+static LOGO_DATA: [u8; 512] = [
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0xC0,
+    0xE0, 0xF0, 0xF8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x3C, 0x3C, 0xC3, 0xC3, 0xC3, 0xC3, 0xC3, 0xC3, 0x03, 0x03,
+    0x00, 0x00, 0xFF, 0xFF, 0xC0, 0xC0, 0xC0, 0xC0, 0xC0, 0xC0, 0xFF, 0xFF, 0x00, 0x00, 0xFF, 0xFF,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xFF, 0xFF, 0x00, 0x00, 0x03, 0x03, 0x03, 0x03, 0xFF, 0xFF,
+    0x03, 0x03, 0x03, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0xFF, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xFF, 0xFF, 0x00, 0x00, 0xFF, 0xFF,
+    0xC3, 0xC3, 0xC3, 0xC3, 0xC3, 0xC3, 0x3C, 0x3C, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0xF8, 0xF8, 0xF8, 0xF8, 0xF8, 0xF8, 0xF8, 0xF8, 0xF8, 0xFC, 0xFE, 0xFF, 0xFF, 0xFF,
+    0xFF, 0xFF, 0xFF, 0x00, 0x00, 0x08, 0x18, 0x30, 0x60, 0xC0, 0x80, 0xC0, 0x60, 0x30, 0x18, 0x08,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x0F, 0x0F,
+    0x00, 0x00, 0x3F, 0x3F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x3F, 0x3F, 0x00, 0x00, 0x0F, 0x0F,
+    0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x0F, 0x0F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x3F, 0x3F,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x0F, 0x0F, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x0F, 0x0F, 0x00, 0x00, 0x3F, 0x3F,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x1F, 0x1F, 0x1F, 0x1F, 0x1F, 0x1F, 0x1F, 0x1F, 0x1F, 0x3F, 0x7F, 0xFF, 0xFF, 0xFF,
+    0xFF, 0xFF, 0xFF, 0x00, 0x00, 0x10, 0x18, 0x0C, 0x06, 0x03, 0x01, 0x03, 0x06, 0x0C, 0x18, 0x10,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xFF, 0xFF, 0x03, 0x03, 0x03, 0x03, 0x0C, 0x0C, 0xF0, 0xF0,
+    0x00, 0x00, 0xFF, 0xFF, 0xC3, 0xC3, 0xC3, 0xC3, 0xC3, 0xC3, 0x03, 0x03, 0x00, 0x00, 0xFF, 0xFF,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xFF, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x03, 0x03, 0xFF, 0xFF,
+    0x03, 0x03, 0x00, 0x00, 0x00, 0x00, 0xFC, 0xFC, 0x03, 0x03, 0x03, 0x03, 0x03, 0x03, 0x0C, 0x0C,
+    0x00, 0x00, 0xFF, 0xFF, 0xC3, 0xC3, 0xC3, 0xC3, 0xC3, 0xC3, 0x03, 0x03, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x03,
+    0x07, 0x0F, 0x1F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x3F, 0x3F, 0x30, 0x30, 0x30, 0x30, 0x0C, 0x0C, 0x03, 0x03,
+    0x00, 0x00, 0x3F, 0x3F, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x00, 0x00, 0x03, 0x03,
+    0x0C, 0x0C, 0x30, 0x30, 0x0C, 0x0C, 0x03, 0x03, 0x00, 0x00, 0x00, 0x00, 0x30, 0x30, 0x3F, 0x3F,
+    0x30, 0x30, 0x00, 0x00, 0x00, 0x00, 0x0F, 0x0F, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x0C, 0x0C,
+    0x00, 0x00, 0x3F, 0x3F, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+];
+//SAFETY: LOGO_DATA is in progmem and is exactly 128x32 pixels worth
+pub static LOGO: Bitmap =
+    unsafe { Bitmap::from_progmem(128, 32, core::ptr::addr_of!(LOGO_DATA).cast()) };
+
+//alarm.pbm, 8x8
+#[rustfmt::skip]
+#[link_section = ".progmem.data"]
+static ALARM_DATA: [u8; 8] = [
+    0x20, 0x30, 0x3E, 0x7F, 0x7F, 0x3E, 0x30, 0x20,
+];
+//SAFETY: ALARM_DATA is in progmem and is exactly 8x8 pixels worth
+pub static ALARM: Bitmap =
+    unsafe { Bitmap::from_progmem(8, 8, core::ptr::addr_of!(ALARM_DATA).cast()) };
+
+//error.pbm, 8x8
+#[rustfmt::skip]
+#[link_section = ".progmem.data"]
+static ERROR_DATA: [u8; 8] = [
+    0x3E, 0x7F, 0x7F, 0x51, 0x51, 0x7F, 0x7F, 0x3E,
+];
+//SAFETY: ERROR_DATA is in progmem and is exactly 8x8 pixels worth
+pub static ERROR: Bitmap =
+    unsafe { Bitmap::from_progmem(8, 8, core::ptr::addr_of!(ERROR_DATA).cast()) };
+
+//mute.pbm, 8x8
+#[rustfmt::skip]
+#[link_section = ".progmem.data"]
+static MUTE_DATA: [u8; 8] = [
+    0x1C, 0x1C, 0x3E, 0x7F, 0x00, 0x0A, 0x04, 0x0A,
+];
+//SAFETY: MUTE_DATA is in progmem and is exactly 8x8 pixels worth
+pub static MUTE: Bitmap =
+    unsafe { Bitmap::from_progmem(8, 8, core::ptr::addr_of!(MUTE_DATA).cast()) };
@@ -0,0 +1,114 @@
+///Finds the frequency our buzzer is loudest at
+///
+/// Piezo buzzers have a resonant peak that varies quite a bit between parts, and being off of it costs a lot
+/// of volume. This sweeps [crate::tone] from [SWEEP_START] to [SWEEP_END] while listening with the mic, and
+/// keeps the frequency that measured the loudest. The result is saved in EEPROM so it only has to be done once.
+/// A mic that's clipping hears everything as equally loud, and one that's disconnected hears nothing, so those
+/// get reported instead of picking a frequency.
+use crate::{no_tone, tone};
+use arduino_hal::Eeprom;
+use ufmt::uWrite;
+use unwrap_infallible::UnwrapInfallible as _;
+
+/// What gets used before calibrating, which is what used to be hard-coded
+pub const DEFAULT_FREQUENCY: u16 = 2000;
+
+/// How fast the mic gets sampled with the default ADC prescaler: 16MHz / 128 / 13 cycles per conversion
+const ADC_SAMPLE_RATE: u16 = 9615;
+
+const SWEEP_START: u16 = 1000;
+/// Anything over half the sample rate aliases down to a lower frequency and measures as nonsense,
+/// so this stays a bit under that
+const SWEEP_END: u16 = ADC_SAMPLE_RATE / 2 - 300;
+const SWEEP_STEP: u16 = 50;
+/// Peak-to-peak reading that means the mic is clipping, the ADC tops out at 1023
+const SATURATED_LEVEL: u16 = 1000;
+/// Clipping steps in a row before giving up, a single one could just be a door slamming
+const SATURATED_STEPS: u8 = 3;
+/// Loudest a whole sweep can be and still count as the mic hearing nothing, that's just ADC noise
+const NO_SIGNAL_LEVEL: u16 = 4;
+/// Time for the buzzer (and the room) to settle on the new frequency before measuring
+const SETTLE_MS: u16 = 10;
+
+//Fresh EEPROM reads back 0xFF, so a magic byte marks that a frequency was actually saved
+const EEPROM_MAGIC_OFFSET: u16 = 0;
+const EEPROM_MAGIC: u8 = 0xA5;
+const EEPROM_FREQUENCY_OFFSET: u16 = 1;
+
+/// Frequency saved by a previous [save], if there is one
+pub fn load(eeprom: &Eeprom) -> Option<u16> {
+    if eeprom.read_byte(EEPROM_MAGIC_OFFSET) != EEPROM_MAGIC {
+        return None;
+    }
+    let frequency = u16::from_le_bytes([
+        eeprom.read_byte(EEPROM_FREQUENCY_OFFSET),
+        eeprom.read_byte(EEPROM_FREQUENCY_OFFSET + 1),
+    ]);
+    (SWEEP_START..=SWEEP_END)
+        .contains(&frequency)
+        .then_some(frequency)
+}
+
+pub fn save(eeprom: &mut Eeprom, frequency: u16) {
+    let [low, high] = frequency.to_le_bytes();
+    eeprom.write_byte(EEPROM_FREQUENCY_OFFSET, low);
+    eeprom.write_byte(EEPROM_FREQUENCY_OFFSET + 1, high);
+    //Magic goes last, so a reset halfway through doesn't leave a half written frequency around
+    eeprom.write_byte(EEPROM_MAGIC_OFFSET, EEPROM_MAGIC);
+}
+
+/// Why [sweep] couldn't pick a frequency
+pub enum SweepError {
+    /// The mic clipped for [SATURATED_STEPS] steps in a row, so the sweep can't tell frequencies apart.
+    /// Move the buzzer further away from the mic (or turn down the mic's gain) and try again
+    Saturated,
+    /// Nothing over [NO_SIGNAL_LEVEL] the whole sweep, the mic is most likely disconnected (or the buzzer is)
+    NoSignal,
+}
+
+/// Runs the sweep and returns the loudest frequency. `measure` should return the current mic level
+/// (higher is louder), every step also gets logged to `serial` as `<Hz>,<level>`. When several frequencies
+/// in a row tie for the loudest it's the one in the middle of them, the resonant peak is somewhere in there.
+pub fn sweep<W: uWrite<Error = core::convert::Infallible>>(
+    tc2: &avr_device::atmega328p::TC2,
+    mut measure: impl FnMut() -> u16,
+    serial: &mut W,
+) -> Result<u16, SweepError> {
+    //Loudest level so far, and the first and last frequency of the run of steps that measured it
+    let mut loudest = (0, DEFAULT_FREQUENCY, DEFAULT_FREQUENCY);
+    let mut on_plateau = false;
+    let mut saturated_steps = 0;
+    for frequency in (SWEEP_START..=SWEEP_END).step_by(SWEEP_STEP as usize) {
+        tone(tc2, frequency);
+        arduino_hal::delay_ms(SETTLE_MS);
+        let level = measure();
+        ufmt::uwriteln!(serial, "{},{}\r", frequency, level).unwrap_infallible();
+
+        saturated_steps = if level >= SATURATED_LEVEL {
+            saturated_steps + 1
+        } else {
+            0
+        };
+        if saturated_steps >= SATURATED_STEPS {
+            no_tone(tc2);
+            return Err(SweepError::Saturated);
+        }
+
+        if level > loudest.0 {
+            loudest = (level, frequency, frequency);
+            on_plateau = true;
+        } else if level == loudest.0 && on_plateau {
+            loudest.2 = frequency;
+        } else {
+            on_plateau = false;
+        }
+    }
+    no_tone(tc2);
+
+    //Middle of the plateau, lined up with the steps
+    let (level, first, last) = loudest;
+    if level <= NO_SIGNAL_LEVEL {
+        return Err(SweepError::NoSignal);
+    }
+    Ok(first + (last - first) / SWEEP_STEP / 2 * SWEEP_STEP)
+}
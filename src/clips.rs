@@ -0,0 +1,305 @@
+//Generated by tools/wav2progmem.py, do not edit by hand
+//Regenerate with: python3 tools/wav2progmem.py assets/quiet_please.wav > src/clips.rs
+use crate::pcm::Clip;
+
+//quiet_please.wav, 0.60s
+#[rustfmt::skip]
+#[link_section = ".progmem.data"]
+static QUIET_PLEASE_SAMPLES: [u8; 4687] = [
+    0x80, 0xEA, 0xFA, 0xC2, 0x8F, 0x6B, 0x36, 0x07, 0x22, 0x8C, 0xE9, 0xF1, 0xBD, 0x8B, 0x63, 0x2F,
+    0x0A, 0x31, 0x9A, 0xEB, 0xE9, 0xB5, 0x87, 0x5E, 0x27, 0x0A, 0x3D, 0xA9, 0xF0, 0xE3, 0xAD, 0x83,
+    0x59, 0x20, 0x08, 0x47, 0xB9, 0xF8, 0xDF, 0xA5, 0x7E, 0x55, 0x1A, 0x05, 0x50, 0xC4, 0xFA, 0xD8,
+    0x9F, 0x78, 0x4E, 0x19, 0x12, 0x60, 0xC9, 0xF3, 0xD0, 0x9B, 0x74, 0x46, 0x16, 0x1B, 0x70, 0xD2,
+    0xEF, 0xC8, 0x96, 0x70, 0x3F, 0x11, 0x22, 0x7F, 0xDD, 0xEE, 0xC0, 0x90, 0x6C, 0x39, 0x0B, 0x25,
+    0x8C, 0xE9, 0xEF, 0xB8, 0x8A, 0x68, 0x34, 0x0A, 0x2E, 0x98, 0xEA, 0xE8, 0xB3, 0x86, 0x61, 0x2E,
+    0x10, 0x3E, 0xA3, 0xE9, 0xDF, 0xAC, 0x82, 0x5B, 0x28, 0x13, 0x4B, 0xB0, 0xEB, 0xD8, 0xA6, 0x7F,
+    0x55, 0x21, 0x13, 0x56, 0xBE, 0xEF, 0xD2, 0x9E, 0x7B, 0x51, 0x1B, 0x10, 0x5F, 0xCD, 0xF4, 0xCD,
+    0x98, 0x76, 0x4C, 0x19, 0x1A, 0x6D, 0xCF, 0xED, 0xC6, 0x94, 0x70, 0x44, 0x19, 0x25, 0x7B, 0xD4,
+    0xE8, 0xBE, 0x91, 0x6D, 0x3D, 0x16, 0x2F, 0x89, 0xDB, 0xE4, 0xB6, 0x8C, 0x69, 0x37, 0x12, 0x34,
+    0x98, 0xE6, 0xE2, 0xB0, 0x87, 0x66, 0x31, 0x0E, 0x39, 0xA3, 0xEA, 0xDE, 0xAA, 0x83, 0x5F, 0x2E,
+    0x16, 0x48, 0xAB, 0xE6, 0xD6, 0xA5, 0x7F, 0x59, 0x2A, 0x1B, 0x56, 0xB5, 0xE6, 0xCF, 0x9F, 0x7B,
+    0x53, 0x24, 0x1E, 0x63, 0xC2, 0xE7, 0xC8, 0x99, 0x77, 0x4E, 0x1D, 0x1E, 0x6E, 0xCF, 0xEB, 0xC2,
+    0x92, 0x75, 0x49, 0x1A, 0x23, 0x78, 0xD3, 0xE7, 0xBD, 0x8F, 0x6F, 0x43, 0x1C, 0x30, 0x85, 0xD5,
+    0xE0, 0xB6, 0x8C, 0x6A, 0x3D, 0x1C, 0x3A, 0x92, 0xD9, 0xDA, 0xAE, 0x88, 0x66, 0x37, 0x1A, 0x42,
+    0x9F, 0xE0, 0xD7, 0xA8, 0x83, 0x62, 0x31, 0x15, 0x47, 0xAD, 0xE8, 0xD5, 0xA2, 0x7F, 0x5D, 0x2E,
+    0x1C, 0x54, 0xB3, 0xE3, 0xCD, 0x9E, 0x7B, 0x57, 0x2B, 0x23, 0x62, 0xBA, 0xE1, 0xC6, 0x99, 0x78,
+    0x51, 0x27, 0x28, 0x6E, 0xC3, 0xDF, 0xBF, 0x94, 0x74, 0x4C, 0x21, 0x2A, 0x7A, 0xCF, 0xE1, 0xB9,
+    0x8E, 0x72, 0x47, 0x1B, 0x2C, 0x83, 0xD5, 0xDF, 0xB3, 0x8A, 0x6C, 0x43, 0x1F, 0x39, 0x8E, 0xD5,
+    0xD8, 0xAD, 0x87, 0x67, 0x3D, 0x22, 0x45, 0x99, 0xD7, 0xD2, 0xA8, 0x84, 0x63, 0x37, 0x21, 0x4F,
+    0xA6, 0xDB, 0xCD, 0xA1, 0x81, 0x5F, 0x31, 0x1F, 0x56, 0xB2, 0xE2, 0xCA, 0x9B, 0x7D, 0x5C, 0x2D,
+    0x21, 0x5F, 0xB8, 0xE0, 0xC4, 0x97, 0x79, 0x55, 0x2C, 0x2A, 0x6B, 0xBD, 0xDB, 0xBE, 0x94, 0x75,
+    0x50, 0x2A, 0x32, 0x77, 0xC4, 0xD8, 0xB7, 0x90, 0x71, 0x4A, 0x26, 0x36, 0x84, 0xCE, 0xD7, 0xB0,
+    0x8B, 0x6F, 0x44, 0x20, 0x38, 0x8F, 0xD8, 0xD7, 0xAB, 0x87, 0x6A, 0x41, 0x23, 0x43, 0x96, 0xD5,
+    0xD0, 0xA6, 0x83, 0x66, 0x3D, 0x27, 0x4F, 0xA0, 0xD5, 0xCA, 0xA1, 0x80, 0x60, 0x38, 0x29, 0x59,
+    0xAA, 0xD6, 0xC4, 0x9C, 0x7E, 0x5C, 0x32, 0x29, 0x62, 0xB6, 0xDB, 0xC0, 0x95, 0x7A, 0x58, 0x2C,
+    0x27, 0x69, 0xBE, 0xDC, 0xBB, 0x92, 0x76, 0x54, 0x2D, 0x31, 0x75, 0xC1, 0xD6, 0xB6, 0x8F, 0x73,
+    0x4E, 0x2D, 0x3B, 0x80, 0xC5, 0xD1, 0xB0, 0x8B, 0x6F, 0x49, 0x2B, 0x41, 0x8C, 0xCC, 0xCE, 0xA9,
+    0x88, 0x6C, 0x44, 0x27, 0x46, 0x96, 0xD4, 0xCD, 0xA4, 0x84, 0x69, 0x3F, 0x26, 0x4C, 0x9E, 0xD5,
+    0xC9, 0x9F, 0x80, 0x63, 0x3D, 0x2D, 0x58, 0xA6, 0xD2, 0xC2, 0x9B, 0x7D, 0x5E, 0x39, 0x30, 0x63,
+    0xAE, 0xD2, 0xBD, 0x97, 0x7B, 0x5A, 0x34, 0x32, 0x6D, 0xB8, 0xD3, 0xB7, 0x92, 0x78, 0x56, 0x2F,
+    0x32, 0x74, 0xC2, 0xD6, 0xB3, 0x8D, 0x74, 0x52, 0x2E, 0x39, 0x7D, 0xC3, 0xD0, 0xAE, 0x8A, 0x71,
+    0x4E, 0x30, 0x43, 0x87, 0xC5, 0xCA, 0xA8, 0x87, 0x6C, 0x49, 0x30, 0x4B, 0x92, 0xC9, 0xC7, 0xA4,
+    0x85, 0x69, 0x44, 0x2E, 0x50, 0x9D, 0xCF, 0xC5, 0x9E, 0x81, 0x67, 0x3F, 0x29, 0x55, 0xA6, 0xD3,
+    0xC1, 0x99, 0x7E, 0x63, 0x3D, 0x31, 0x61, 0xAB, 0xCF, 0xBB, 0x96, 0x7A, 0x5D, 0x3A, 0x37, 0x6B,
+    0xB1, 0xCD, 0xB5, 0x92, 0x78, 0x58, 0x37, 0x3B, 0x76, 0xB9, 0xCC, 0xB0, 0x8F, 0x76, 0x54, 0x32,
+    0x3C, 0x7E, 0xC2, 0xCD, 0xAC, 0x8A, 0x73, 0x51, 0x30, 0x40, 0x86, 0xC5, 0xCA, 0xA7, 0x87, 0x6F,
+    0x4D, 0x34, 0x4A, 0x8F, 0xC5, 0xC5, 0xA3, 0x85, 0x6B, 0x49, 0x35, 0x53, 0x98, 0xC6, 0xBF, 0x9D,
+    0x82, 0x67, 0x44, 0x35, 0x5B, 0xA2, 0xCA, 0xBC, 0x98, 0x7F, 0x64, 0x3F, 0x32, 0x61, 0xAC, 0xD0,
+    0xB9, 0x93, 0x7C, 0x61, 0x3D, 0x37, 0x69, 0xAF, 0xCB, 0xB4, 0x91, 0x78, 0x5C, 0x3C, 0x3E, 0x73,
+    0xB3, 0xC8, 0xAF, 0x8F, 0x76, 0x57, 0x39, 0x43, 0x7D, 0xB9, 0xC6, 0xAA, 0x8B, 0x73, 0x52, 0x37,
+    0x47, 0x87, 0xC0, 0xC5, 0xA4, 0x88, 0x71, 0x4E, 0x33, 0x48, 0x8E, 0xC6, 0xC4, 0xA1, 0x84, 0x6D,
+    0x4C, 0x37, 0x53, 0x95, 0xC4, 0xBF, 0x9D, 0x82, 0x69, 0x48, 0x39, 0x5C, 0x9C, 0xC4, 0xBA, 0x99,
+    0x7F, 0x65, 0x44, 0x3A, 0x64, 0xA5, 0xC5, 0xB5, 0x95, 0x7D, 0x62, 0x40, 0x3A, 0x6B, 0xAE, 0xCA,
+    0xB1, 0x8F, 0x7A, 0x60, 0x3C, 0x3C, 0x72, 0xB3, 0xC8, 0xAE, 0x8D, 0x77, 0x5B, 0x3E, 0x44, 0x7A,
+    0xB5, 0xC3, 0xA9, 0x8A, 0x74, 0x56, 0x3D, 0x4B, 0x84, 0xB9, 0xC0, 0xA4, 0x88, 0x71, 0x52, 0x3B,
+    0x4F, 0x8C, 0xBE, 0xBD, 0x9F, 0x85, 0x6F, 0x4E, 0x38, 0x52, 0x95, 0xC5, 0xBD, 0x9A, 0x82, 0x6C,
+    0x4B, 0x39, 0x59, 0x9B, 0xC3, 0xB8, 0x97, 0x7F, 0x67, 0x49, 0x3E, 0x63, 0xA1, 0xC1, 0xB3, 0x95,
+    0x7D, 0x64, 0x46, 0x41, 0x6B, 0xA7, 0xC0, 0xAE, 0x91, 0x7A, 0x60, 0x42, 0x42, 0x74, 0xB0, 0xC2,
+    0xAA, 0x8D, 0x79, 0x5D, 0x3D, 0x41, 0x7A, 0xB6, 0xC3, 0xA6, 0x89, 0x75, 0x5A, 0x3F, 0x4A, 0x82,
+    0xB7, 0xBE, 0xA3, 0x88, 0x72, 0x55, 0x40, 0x52, 0x89, 0xB8, 0xBA, 0x9F, 0x85, 0x6F, 0x52, 0x3F,
+    0x58, 0x92, 0xBC, 0xB7, 0x9A, 0x82, 0x6C, 0x4E, 0x3D, 0x5C, 0x9B, 0xC0, 0xB5, 0x96, 0x7F, 0x6A,
+    0x4A, 0x3D, 0x61, 0xA1, 0xC1, 0xB2, 0x93, 0x7D, 0x66, 0x4A, 0x43, 0x6A, 0xA4, 0xBF, 0xAE, 0x91,
+    0x7B, 0x62, 0x47, 0x47, 0x72, 0xA9, 0xBC, 0xA9, 0x8E, 0x79, 0x5F, 0x44, 0x4A, 0x7B, 0xB0, 0xBD,
+    0xA5, 0x8A, 0x76, 0x5B, 0x40, 0x4A, 0x81, 0xB7, 0xBE, 0xA1, 0x87, 0x74, 0x59, 0x40, 0x50, 0x88,
+    0xB7, 0xB9, 0x9E, 0x84, 0x70, 0x55, 0x43, 0x58, 0x8E, 0xB7, 0xB5, 0x9A, 0x82, 0x6E, 0x52, 0x44,
+    0x5F, 0x96, 0xB9, 0xB1, 0x96, 0x81, 0x6B, 0x4E, 0x44, 0x65, 0x9D, 0xBC, 0xAE, 0x92, 0x7E, 0x68,
+    0x4B, 0x42, 0x69, 0xA6, 0xBF, 0xAC, 0x8F, 0x7B, 0x65, 0x4A, 0x47, 0x71, 0xA7, 0xBB, 0xA8, 0x8D,
+    0x78, 0x61, 0x49, 0x4D, 0x79, 0xAB, 0xB9, 0xA4, 0x8A, 0x77, 0x5D, 0x47, 0x51, 0x81, 0xAF, 0xB7,
+    0x9F, 0x88, 0x75, 0x5B, 0x44, 0x53, 0x88, 0xB6, 0xB7, 0x9B, 0x85, 0x74, 0x57, 0x42, 0x56, 0x8E,
+    0xB8, 0xB5, 0x99, 0x82, 0x6F, 0x56, 0x46, 0x5E, 0x93, 0xB7, 0xB0, 0x96, 0x80, 0x6C, 0x52, 0x48,
+    0x66, 0x99, 0xB7, 0xAC, 0x92, 0x7E, 0x69, 0x4F, 0x49, 0x6C, 0xA0, 0xB8, 0xA9, 0x8F, 0x7D, 0x66,
+    0x4B, 0x48, 0x71, 0xA7, 0xBB, 0xA6, 0x8B, 0x7A, 0x65, 0x4A, 0x4B, 0x77, 0xAA, 0xB8, 0xA3, 0x89,
+    0x77, 0x61, 0x4B, 0x52, 0x7E, 0xAC, 0xB5, 0x9F, 0x87, 0x75, 0x5E, 0x4A, 0x57, 0x86, 0xAF, 0xB2,
+    0x9B, 0x85, 0x72, 0x5A, 0x48, 0x5A, 0x8D, 0xB3, 0xB1, 0x97, 0x83, 0x71, 0x56, 0x45, 0x5D, 0x93,
+    0xB8, 0xAF, 0x94, 0x81, 0x6F, 0x55, 0x49, 0x64, 0x97, 0xB5, 0xAC, 0x92, 0x7F, 0x6B, 0x52, 0x4C,
+    0x6B, 0x9C, 0xB4, 0xA7, 0x8F, 0x7D, 0x68, 0x50, 0x4E, 0x72, 0xA2, 0xB4, 0xA4, 0x8C, 0x7B, 0x65,
+    0x4D, 0x4F, 0x79, 0xA8, 0xB6, 0xA1, 0x89, 0x79, 0x63, 0x4A, 0x50, 0x7E, 0xAC, 0xB4, 0x9E, 0x86,
+    0x76, 0x60, 0x4C, 0x56, 0x84, 0xAC, 0xB1, 0x9B, 0x85, 0x74, 0x5D, 0x4D, 0x5C, 0x8A, 0xAE, 0xAD,
+    0x97, 0x84, 0x72, 0x5A, 0x4C, 0x61, 0x91, 0xB1, 0xAB, 0x94, 0x82, 0x70, 0x56, 0x4A, 0x65, 0x97,
+    0xB6, 0xAA, 0x91, 0x7E, 0x6D, 0x54, 0x4C, 0x6A, 0x9B, 0xB4, 0xA6, 0x8E, 0x7C, 0x6A, 0x53, 0x50,
+    0x71, 0x9E, 0xB2, 0xA3, 0x8C, 0x7B, 0x68, 0x52, 0x53, 0x78, 0xA3, 0xB0, 0x9F, 0x89, 0x79, 0x64,
+    0x50, 0x56, 0x7E, 0xA8, 0xB0, 0x9C, 0x87, 0x77, 0x62, 0x4C, 0x55, 0x83, 0xAE, 0xB1, 0x99, 0x84,
+    0x76, 0x60, 0x4D, 0x5B, 0x88, 0xAD, 0xAC, 0x96, 0x83, 0x73, 0x5D, 0x50, 0x62, 0x8E, 0xAD, 0xA9,
+    0x94, 0x82, 0x71, 0x5A, 0x50, 0x67, 0x94, 0xAE, 0xA7, 0x91, 0x80, 0x6E, 0x57, 0x4F, 0x6C, 0x9A,
+    0xB1, 0xA4, 0x8D, 0x7E, 0x6C, 0x53, 0x4E, 0x6F, 0x9F, 0xB2, 0xA2, 0x8B, 0x7C, 0x69, 0x54, 0x54,
+    0x76, 0xA1, 0xAE, 0x9F, 0x8A, 0x7A, 0x66, 0x53, 0x58, 0x7C, 0xA4, 0xAD, 0x9B, 0x87, 0x78, 0x64,
+    0x52, 0x5C, 0x83, 0xA8, 0xAC, 0x97, 0x85, 0x76, 0x61, 0x4F, 0x5D, 0x89, 0xAC, 0xAB, 0x95, 0x82,
+    0x75, 0x5F, 0x4F, 0x61, 0x8C, 0xAD, 0xA9, 0x92, 0x81, 0x72, 0x5C, 0x52, 0x67, 0x91, 0xAC, 0xA5,
+    0x90, 0x7F, 0x6F, 0x5A, 0x53, 0x6D, 0x96, 0xAC, 0xA3, 0x8E, 0x7E, 0x6D, 0x57, 0x54, 0x72, 0x9C,
+    0xAD, 0x9F, 0x8B, 0x7C, 0x6B, 0x55, 0x53, 0x76, 0xA2, 0xAF, 0x9E, 0x88, 0x7B, 0x69, 0x54, 0x58,
+    0x7B, 0xA2, 0xAC, 0x9A, 0x87, 0x78, 0x66, 0x55, 0x5C, 0x81, 0xA4, 0xA9, 0x97, 0x85, 0x77, 0x63,
+    0x54, 0x61, 0x87, 0xA7, 0xA8, 0x95, 0x83, 0x74, 0x60, 0x53, 0x63, 0x8C, 0xAA, 0xA6, 0x91, 0x81,
+    0x74, 0x5E, 0x51, 0x65, 0x91, 0xAC, 0xA5, 0x8F, 0x7F, 0x71, 0x5D, 0x54, 0x6C, 0x94, 0xAB, 0xA2,
+    0x8D, 0x7E, 0x6E, 0x5A, 0x57, 0x72, 0x98, 0xA9, 0x9E, 0x8B, 0x7C, 0x6B, 0x59, 0x59, 0x77, 0x9D,
+    0xA9, 0x9C, 0x89, 0x7B, 0x6A, 0x56, 0x59, 0x7B, 0xA2, 0xAB, 0x99, 0x86, 0x79, 0x68, 0x55, 0x5C,
+    0x7F, 0xA4, 0xA9, 0x97, 0x85, 0x77, 0x65, 0x56, 0x61, 0x85, 0xA4, 0xA6, 0x94, 0x83, 0x75, 0x63,
+    0x57, 0x65, 0x8A, 0xA5, 0xA3, 0x91, 0x82, 0x73, 0x60, 0x56, 0x68, 0x90, 0xA8, 0xA2, 0x8F, 0x81,
+    0x72, 0x5E, 0x55, 0x6C, 0x95, 0xAB, 0xA1, 0x8C, 0x7F, 0x70, 0x5C, 0x57, 0x70, 0x97, 0xA8, 0x9D,
+    0x8A, 0x7C, 0x6D, 0x5C, 0x5A, 0x76, 0x99, 0xA7, 0x9A, 0x88, 0x7B, 0x6B, 0x5A, 0x5E, 0x7B, 0x9D,
+    0xA6, 0x98, 0x87, 0x7A, 0x69, 0x58, 0x5F, 0x80, 0xA1, 0xA6, 0x95, 0x85, 0x79, 0x67, 0x56, 0x5F,
+    0x84, 0xA4, 0xA6, 0x93, 0x82, 0x77, 0x65, 0x58, 0x65, 0x89, 0xA4, 0xA3, 0x91, 0x82, 0x75, 0x63,
+    0x59, 0x6A, 0x8D, 0xA4, 0xA0, 0x8F, 0x80, 0x72, 0x61, 0x5A, 0x6E, 0x92, 0xA6, 0x9E, 0x8C, 0x7F,
+    0x71, 0x5E, 0x59, 0x71, 0x97, 0xA8, 0x9D, 0x89, 0x7D, 0x6F, 0x5C, 0x59, 0x74, 0x99, 0xA7, 0x9A,
+    0x88, 0x7C, 0x6D, 0x5D, 0x5E, 0x7A, 0x9B, 0xA4, 0x97, 0x86, 0x7A, 0x6A, 0x5C, 0x61, 0x7F, 0x9D,
+    0xA3, 0x94, 0x85, 0x79, 0x68, 0x5A, 0x63, 0x83, 0xA0, 0xA2, 0x91, 0x83, 0x77, 0x66, 0x58, 0x64,
+    0x89, 0xA5, 0xA2, 0x90, 0x82, 0x76, 0x65, 0x59, 0x68, 0x8C, 0xA4, 0xA0, 0x8E, 0x80, 0x73, 0x63,
+    0x5C, 0x6E, 0x8F, 0xA3, 0x9D, 0x8C, 0x7F, 0x72, 0x61, 0x5D, 0x72, 0x94, 0xA3, 0x9A, 0x8A, 0x7E,
+    0x70, 0x5F, 0x5D, 0x76, 0x98, 0xA4, 0x99, 0x88, 0x7D, 0x6E, 0x5D, 0x5C, 0x79, 0x9B, 0xA5, 0x97,
+    0x86, 0x7A, 0x6C, 0x5D, 0x61, 0x7E, 0x9C, 0xA3, 0x94, 0x85, 0x79, 0x6A, 0x5D, 0x65, 0x82, 0x9D,
+    0xA0, 0x91, 0x83, 0x77, 0x67, 0x5C, 0x68, 0x87, 0xA0, 0x9F, 0x8F, 0x81, 0x77, 0x66, 0x5B, 0x6A,
+    0x8C, 0xA3, 0x9E, 0x8C, 0x80, 0x76, 0x64, 0x5B, 0x6D, 0x8F, 0xA3, 0x9C, 0x8C, 0x7F, 0x73, 0x63,
+    0x5E, 0x72, 0x91, 0xA2, 0x9A, 0x8A, 0x7E, 0x71, 0x62, 0x60, 0x76, 0x95, 0xA1, 0x97, 0x88, 0x7D,
+    0x6F, 0x5F, 0x60, 0x7A, 0x98, 0xA1, 0x95, 0x86, 0x7B, 0x6D, 0x5E, 0x61, 0x7E, 0x9D, 0xA2, 0x93,
+    0x85, 0x7A, 0x6B, 0x5E, 0x64, 0x81, 0x9C, 0x9F, 0x91, 0x83, 0x78, 0x69, 0x5F, 0x68, 0x86, 0x9D,
+    0x9D, 0x8F, 0x82, 0x77, 0x68, 0x5F, 0x6B, 0x8A, 0x9E, 0x9C, 0x8D, 0x81, 0x75, 0x66, 0x5E, 0x6F,
+    0x8E, 0xA0, 0x9B, 0x8B, 0x80, 0x74, 0x63, 0x5C, 0x71, 0x91, 0xA2, 0x99, 0x89, 0x7D, 0x72, 0x63,
+    0x60, 0x75, 0x93, 0xA0, 0x97, 0x88, 0x7C, 0x70, 0x62, 0x63, 0x7A, 0x96, 0x9F, 0x94, 0x87, 0x7C,
+    0x6E, 0x61, 0x64, 0x7E, 0x99, 0x9E, 0x92, 0x84, 0x7A, 0x6C, 0x60, 0x65, 0x81, 0x9C, 0x9F, 0x90,
+    0x83, 0x7A, 0x6B, 0x5E, 0x67, 0x85, 0x9E, 0x9E, 0x8F, 0x82, 0x78, 0x6A, 0x60, 0x6C, 0x88, 0x9C,
+    0x9B, 0x8D, 0x80, 0x75, 0x68, 0x61, 0x6F, 0x8C, 0x9D, 0x99, 0x8B, 0x80, 0x74, 0x66, 0x61, 0x73,
+    0x8F, 0x9E, 0x97, 0x89, 0x7F, 0x74, 0x64, 0x60, 0x76, 0x93, 0xA0, 0x96, 0x87, 0x7D, 0x72, 0x64,
+    0x63, 0x79, 0x94, 0x9E, 0x94, 0x85, 0x7C, 0x70, 0x63, 0x65, 0x7D, 0x96, 0x9D, 0x92, 0x84, 0x7A,
+    0x6E, 0x62, 0x68, 0x81, 0x98, 0x9B, 0x8F, 0x83, 0x7A, 0x6C, 0x61, 0x6A, 0x84, 0x9B, 0x9B, 0x8D,
+    0x82, 0x79, 0x6A, 0x5F, 0x6A, 0x88, 0x9D, 0x9B, 0x8C, 0x80, 0x77, 0x6A, 0x62, 0x6F, 0x8A, 0x9C,
+    0x98, 0x8B, 0x80, 0x75, 0x68, 0x63, 0x72, 0x8D, 0x9C, 0x96, 0x89, 0x7E, 0x74, 0x67, 0x64, 0x76,
+    0x91, 0x9C, 0x94, 0x87, 0x7E, 0x72, 0x65, 0x64, 0x79, 0x94, 0x9D, 0x93, 0x86, 0x7C, 0x71, 0x63,
+    0x65, 0x7C, 0x97, 0x9D, 0x91, 0x84, 0x7B, 0x6F, 0x64, 0x68, 0x7F, 0x97, 0x9B, 0x8F, 0x83, 0x79,
+    0x6D, 0x63, 0x6B, 0x83, 0x97, 0x99, 0x8E, 0x82, 0x78, 0x6B, 0x63, 0x6D, 0x86, 0x9A, 0x98, 0x8C,
+    0x81, 0x77, 0x6A, 0x62, 0x6F, 0x8B, 0x9D, 0x98, 0x89, 0x80, 0x76, 0x68, 0x63, 0x72, 0x8D, 0x9B,
+    0x95, 0x88, 0x7F, 0x75, 0x68, 0x65, 0x75, 0x8F, 0x9A, 0x94, 0x87, 0x7E, 0x73, 0x68, 0x67, 0x79,
+    0x91, 0x9A, 0x92, 0x86, 0x7D, 0x72, 0x66, 0x67, 0x7D, 0x94, 0x9A, 0x90, 0x84, 0x7C, 0x70, 0x64,
+    0x68, 0x80, 0xD7, 0xFF, 0xF0, 0xC2, 0x99, 0x7D, 0x60, 0x35, 0x0D, 0x06, 0x38, 0x8E, 0xDC, 0xFA,
+    0xE6, 0xBB, 0x95, 0x79, 0x59, 0x2E, 0x0A, 0x0E, 0x46, 0x9D, 0xE3, 0xF8, 0xDE, 0xB3, 0x90, 0x75,
+    0x53, 0x27, 0x06, 0x12, 0x52, 0xAC, 0xEE, 0xFA, 0xD8, 0xAB, 0x89, 0x72, 0x4E, 0x21, 0x04, 0x18,
+    0x5F, 0xB8, 0xF1, 0xF4, 0xD0, 0xA6, 0x86, 0x6B, 0x47, 0x1D, 0x08, 0x25, 0x6F, 0xC2, 0xF2, 0xED,
+    0xC7, 0x9F, 0x82, 0x66, 0x40, 0x17, 0x0A, 0x30, 0x7F, 0xCE, 0xF4, 0xE9, 0xC0, 0x99, 0x7E, 0x62,
+    0x39, 0x10, 0x08, 0x37, 0x8D, 0xDA, 0xF8, 0xE3, 0xB8, 0x93, 0x7A, 0x5C, 0x33, 0x10, 0x12, 0x46,
+    0x99, 0xDE, 0xF3, 0xDB, 0xB1, 0x8F, 0x75, 0x56, 0x2D, 0x0F, 0x19, 0x54, 0xA6, 0xE3, 0xF0, 0xD3,
+    0xAA, 0x8B, 0x71, 0x50, 0x26, 0x0C, 0x1F, 0x61, 0xB5, 0xEC, 0xEF, 0xCC, 0xA3, 0x86, 0x6E, 0x4C,
+    0x20, 0x0B, 0x26, 0x6D, 0xBF, 0xED, 0xE9, 0xC5, 0x9E, 0x82, 0x68, 0x45, 0x1E, 0x11, 0x33, 0x7C,
+    0xC7, 0xED, 0xE3, 0xBD, 0x98, 0x7E, 0x63, 0x3D, 0x1A, 0x13, 0x3D, 0x8A, 0xD1, 0xEE, 0xDD, 0xB6,
+    0x94, 0x7B, 0x5F, 0x37, 0x14, 0x13, 0x46, 0x98, 0xDC, 0xF1, 0xD8, 0xAF, 0x8E, 0x76, 0x5A, 0x32,
+    0x14, 0x1C, 0x53, 0xA2, 0xDE, 0xEB, 0xD0, 0xA9, 0x8A, 0x73, 0x53, 0x2D, 0x15, 0x26, 0x62, 0xAE,
+    0xE2, 0xE6, 0xC8, 0xA3, 0x87, 0x6F, 0x4D, 0x27, 0x13, 0x2C, 0x6F, 0xBC, 0xE8, 0xE6, 0xC1, 0x9C,
+    0x82, 0x6B, 0x48, 0x22, 0x13, 0x33, 0x7A, 0xC4, 0xE9, 0xDF, 0xBB, 0x97, 0x7E, 0x66, 0x43, 0x20,
+    0x19, 0x40, 0x88, 0xCA, 0xE7, 0xD8, 0xB4, 0x93, 0x7B, 0x60, 0x3C, 0x1D, 0x1E, 0x4C, 0x95, 0xD3,
+    0xE8, 0xD2, 0xAD, 0x8E, 0x78, 0x5C, 0x36, 0x18, 0x1F, 0x54, 0xA2, 0xDD, 0xE9, 0xCD, 0xA6, 0x89,
+    0x74, 0x57, 0x32, 0x19, 0x28, 0x61, 0xAB, 0xDD, 0xE2, 0xC5, 0xA1, 0x86, 0x6F, 0x51, 0x2D, 0x1B,
+    0x31, 0x6E, 0xB4, 0xDF, 0xDE, 0xBF, 0x9D, 0x83, 0x6C, 0x4C, 0x28, 0x1A, 0x38, 0x7B, 0xC0, 0xE4,
+    0xDB, 0xB8, 0x96, 0x7F, 0x69, 0x47, 0x24, 0x1B, 0x3F, 0x86, 0xC8, 0xE4, 0xD5, 0xB2, 0x92, 0x7B,
+    0x63, 0x41, 0x23, 0x22, 0x4D, 0x91, 0xCD, 0xE1, 0xCE, 0xAC, 0x8F, 0x78, 0x5E, 0x3B, 0x20, 0x27,
+    0x58, 0x9E, 0xD3, 0xE0, 0xC8, 0xA6, 0x8A, 0x75, 0x5A, 0x36, 0x1D, 0x2A, 0x61, 0xA9, 0xDC, 0xE0,
+    0xC3, 0x9F, 0x86, 0x71, 0x54, 0x31, 0x1F, 0x33, 0x6D, 0xB1, 0xDA, 0xDA, 0xBC, 0x9B, 0x83, 0x6D,
+    0x4F, 0x2E, 0x22, 0x3D, 0x79, 0xB9, 0xDB, 0xD5, 0xB6, 0x96, 0x80, 0x69, 0x49, 0x29, 0x22, 0x44,
+    0x85, 0xC4, 0xE0, 0xD1, 0xAF, 0x91, 0x7D, 0x66, 0x45, 0x25, 0x23, 0x4C, 0x8F, 0xCA, 0xDF, 0xCC,
+    0xAA, 0x8D, 0x79, 0x60, 0x40, 0x26, 0x2B, 0x58, 0x99, 0xCD, 0xDA, 0xC6, 0xA4, 0x8A, 0x75, 0x5C,
+    0x3B, 0x24, 0x30, 0x63, 0xA5, 0xD2, 0xD8, 0xBF, 0x9F, 0x86, 0x72, 0x57, 0x36, 0x22, 0x33, 0x6C,
+    0xB0, 0xD9, 0xD8, 0xBA, 0x99, 0x83, 0x6F, 0x53, 0x32, 0x25, 0x3D, 0x77, 0xB5, 0xD7, 0xD2, 0xB4,
+    0x96, 0x7F, 0x6A, 0x4E, 0x30, 0x28, 0x47, 0x83, 0xBD, 0xD7, 0xCC, 0xAD, 0x91, 0x7D, 0x67, 0x48,
+    0x2C, 0x29, 0x50, 0x8E, 0xC6, 0xD9, 0xC8, 0xA7, 0x8D, 0x7A, 0x64, 0x43, 0x28, 0x2C, 0x57, 0x98,
+    0xCA, 0xD8, 0xC3, 0xA3, 0x8A, 0x77, 0x5F, 0x3F, 0x2A, 0x34, 0x62, 0xA0, 0xCC, 0xD3, 0xBC, 0x9E,
+    0x86, 0x73, 0x59, 0x3B, 0x2A, 0x3B, 0x6E, 0xAA, 0xD0, 0xD0, 0xB6, 0x99, 0x84, 0x70, 0x55, 0x36,
+    0x28, 0x3F, 0x77, 0xB5, 0xD7, 0xCF, 0xB1, 0x94, 0x80, 0x6D, 0x51, 0x33, 0x2B, 0x47, 0x81, 0xB9,
+    0xD3, 0xC9, 0xAC, 0x91, 0x7D, 0x69, 0x4C, 0x31, 0x2F, 0x52, 0x8B, 0xBF, 0xD2, 0xC3, 0xA6, 0x8D,
+    0x7B, 0x65, 0x47, 0x2F, 0x32, 0x5A, 0x96, 0xC6, 0xD3, 0xC0, 0xA1, 0x89, 0x78, 0x61, 0x42, 0x2C,
+    0x35, 0x62, 0x9F, 0xCA, 0xD1, 0xBA, 0x9C, 0x86, 0x74, 0x5C, 0x3F, 0x2F, 0x3D, 0x6D, 0xA6, 0xCB,
+    0xCD, 0xB5, 0x98, 0x83, 0x71, 0x58, 0x3C, 0x2F, 0x44, 0x77, 0xAE, 0xCD, 0xC9, 0xAF, 0x93, 0x81,
+    0x6E, 0x53, 0x37, 0x2E, 0x48, 0x80, 0xB8, 0xD3, 0xC7, 0xAA, 0x8F, 0x7E, 0x6B, 0x4F, 0x35, 0x32,
+    0x52, 0x8A, 0xBB, 0xCF, 0xC1, 0xA5, 0x8D, 0x7B, 0x66, 0x4B, 0x35, 0x37, 0x5C, 0x93, 0xC0, 0xCC,
+    0xBC, 0xA0, 0x89, 0x78, 0x62, 0x47, 0x32, 0x3A, 0x64, 0x9C, 0xC6, 0xCC, 0xB7, 0x9A, 0x86, 0x76,
+    0x5F, 0x42, 0x30, 0x3D, 0x6C, 0xA5, 0xC9, 0xCA, 0xB3, 0x97, 0x83, 0x72, 0x5B, 0x40, 0x33, 0x46,
+    0x76, 0xAB, 0xC9, 0xC5, 0xAD, 0x94, 0x81, 0x6F, 0x56, 0x3D, 0x35, 0x4E, 0x80, 0xB2, 0xCA, 0xC2,
+    0xA8, 0x8F, 0x7E, 0x6C, 0x52, 0x39, 0x35, 0x52, 0x89, 0xBB, 0xCE, 0xBF, 0xA3, 0x8C, 0x7C, 0x68,
+    0x4E, 0x38, 0x39, 0x5B, 0x91, 0xBD, 0xCA, 0xBA, 0x9F, 0x89, 0x78, 0x64, 0x4A, 0x38, 0x3F, 0x64,
+    0x99, 0xC0, 0xC7, 0xB4, 0x9A, 0x86, 0x76, 0x61, 0x46, 0x36, 0x42, 0x6D, 0xA2, 0xC4, 0xC7, 0xB1,
+    0x95, 0x84, 0x74, 0x5E, 0x43, 0x34, 0x46, 0x75, 0xA9, 0xC6, 0xC4, 0xAC, 0x92, 0x81, 0x70, 0x59,
+    0x40, 0x38, 0x4F, 0x7E, 0xAE, 0xC5, 0xBE, 0xA6, 0x8F, 0x7E, 0x6D, 0x55, 0x3E, 0x3B, 0x56, 0x88,
+    0xB4, 0xC6, 0xBB, 0xA1, 0x8C, 0x7C, 0x6A, 0x51, 0x3B, 0x3B, 0x5C, 0x91, 0xBB, 0xC9, 0xB8, 0x9E,
+    0x88, 0x79, 0x67, 0x4D, 0x3A, 0x40, 0x65, 0x97, 0xBD, 0xC5, 0xB3, 0x9A, 0x86, 0x77, 0x63, 0x4A,
+    0x3B, 0x46, 0x6E, 0x9F, 0xBF, 0xC2, 0xAE, 0x95, 0x84, 0x74, 0x5F, 0x47, 0x3A, 0x4A, 0x75, 0xA6,
+    0xC3, 0xC0, 0xA9, 0x91, 0x81, 0x72, 0x5B, 0x43, 0x39, 0x4E, 0x7E, 0xAD, 0xC4, 0xBC, 0xA5, 0x8E,
+    0x7E, 0x6E, 0x58, 0x41, 0x3E, 0x57, 0x85, 0xB0, 0xC2, 0xB8, 0xA1, 0x8C, 0x7D, 0x6B, 0x54, 0x40,
+    0x41, 0x5F, 0x8E, 0xB5, 0xC2, 0xB4, 0x9C, 0x89, 0x7B, 0x68, 0x50, 0x3D, 0x42, 0x65, 0x96, 0xBB,
+    0xC4, 0xB1, 0x97, 0x85, 0x78, 0x65, 0x4D, 0x3D, 0x47, 0x6D, 0x9C, 0xBC, 0xBF, 0xAC, 0x94, 0x83,
+    0x75, 0x61, 0x4B, 0x3F, 0x4E, 0x75, 0xA2, 0xBD, 0xBC, 0xA7, 0x91, 0x81, 0x72, 0x5E, 0x48, 0x3F,
+    0x52, 0x7D, 0xA9, 0xC0, 0xBA, 0xA3, 0x8D, 0x80, 0x70, 0x5A, 0x44, 0x3E, 0x57, 0x85, 0xAF, 0xC0,
+    0xB6, 0x9F, 0x8B, 0x7D, 0x6D, 0x57, 0x44, 0x44, 0x5F, 0x8B, 0xB1, 0xBE, 0xB2, 0x9B, 0x88, 0x7B,
+    0x6A, 0x53, 0x42, 0x47, 0x67, 0x93, 0xB5, 0xBD, 0xAE, 0x97, 0x86, 0x78, 0x67, 0x4F, 0x41, 0x49,
+    0x6D, 0x9B, 0xBB, 0xBD, 0xAA, 0x94, 0x83, 0x77, 0x64, 0x4D, 0x41, 0x4E, 0x74, 0xA0, 0xBB, 0xB9,
+    0xA6, 0x90, 0x81, 0x73, 0x60, 0x4B, 0x43, 0x55, 0x7C, 0xA5, 0xBB, 0xB6, 0xA1, 0x8E, 0x7F, 0x71,
+    0x5D, 0x48, 0x44, 0x5A, 0x83, 0xAB, 0xBC, 0xB4, 0x9D, 0x8B, 0x7D, 0x6F, 0x59, 0x45, 0x44, 0x5F,
+    0x8B, 0xB0, 0xBC, 0xB0, 0x9A, 0x88, 0x7B, 0x6C, 0x57, 0x46, 0x4A, 0x67, 0x91, 0xB2, 0xBA, 0xAC,
+    0x97, 0x87, 0x79, 0x68, 0x53, 0x45, 0x4E, 0x6E, 0x98, 0xB5, 0xB8, 0xA8, 0x93, 0x84, 0x77, 0x65,
+    0x50, 0x44, 0x50, 0x74, 0x9F, 0xB9, 0xB8, 0xA5, 0x90, 0x82, 0x74, 0x63, 0x4E, 0x45, 0x55, 0x7B,
+    0xA3, 0xB8, 0xB4, 0xA1, 0x8D, 0x80, 0x72, 0x5F, 0x4C, 0x47, 0x5B, 0x82, 0xA8, 0xB8, 0xB0, 0x9D,
+    0x8B, 0x7E, 0x6F, 0x5B, 0x4A, 0x49, 0x62, 0x8A, 0xAD, 0xB8, 0xAD, 0x98, 0x88, 0x7C, 0x6D, 0x59,
+    0x47, 0x4A, 0x66, 0x91, 0xB0, 0xB8, 0xAB, 0x95, 0x85, 0x79, 0x6A, 0x56, 0x49, 0x50, 0x6E, 0x96,
+    0xB1, 0xB5, 0xA6, 0x93, 0x83, 0x77, 0x66, 0x53, 0x48, 0x54, 0x74, 0x9C, 0xB3, 0xB3, 0xA3, 0x90,
+    0x82, 0x76, 0x63, 0x50, 0x47, 0x57, 0x7C, 0xA2, 0xB7, 0xB2, 0x9E, 0x8C, 0x7F, 0x73, 0x62, 0x4E,
+    0x49, 0x5C, 0x82, 0xA5, 0xB5, 0xAE, 0x9B, 0x8A, 0x7E, 0x71, 0x5E, 0x4E, 0x4D, 0x63, 0x88, 0xA9,
+    0xB4, 0xAB, 0x98, 0x88, 0x7C, 0x6E, 0x5B, 0x4C, 0x4E, 0x68, 0x8E, 0xAD, 0xB5, 0xA8, 0x94, 0x85,
+    0x7A, 0x6C, 0x58, 0x49, 0x4F, 0x6D, 0x94, 0xB0, 0xB4, 0xA5, 0x92, 0x83, 0x78, 0x68, 0x56, 0x4B,
+    0x55, 0x74, 0x99, 0xB0, 0xB1, 0xA1, 0x8F, 0x81, 0x76, 0x65, 0x53, 0x4C, 0x5A, 0x7B, 0x9F, 0xB2,
+    0xAE, 0x9E, 0x8C, 0x80, 0x74, 0x63, 0x51, 0x4C, 0x5D, 0x81, 0xA4, 0xB4, 0xAD, 0x9B, 0x8A, 0x7E,
+    0x72, 0x61, 0x4F, 0x4D, 0x62, 0x86, 0xA7, 0xB3, 0xA9, 0x97, 0x88, 0x7C, 0x6F, 0x5E, 0x4F, 0x51,
+    0x69, 0x8D, 0xA9, 0xB1, 0xA6, 0x94, 0x86, 0x7B, 0x6D, 0x5B, 0x4E, 0x54, 0x6E, 0x93, 0xAC, 0xB0,
+    0xA2, 0x90, 0x84, 0x79, 0x6B, 0x58, 0x4C, 0x55, 0x74, 0x99, 0xAF, 0xB0, 0xA0, 0x8E, 0x82, 0x77,
+    0x67, 0x56, 0x4E, 0x5B, 0x7A, 0x9C, 0xAF, 0xAC, 0x9C, 0x8C, 0x80, 0x74, 0x65, 0x55, 0x50, 0x60,
+    0x81, 0xA0, 0xAF, 0xAA, 0x99, 0x8A, 0x7F, 0x73, 0x62, 0x52, 0x50, 0x64, 0x86, 0xA6, 0xB1, 0xA8,
+    0x96, 0x87, 0x7D, 0x71, 0x60, 0x51, 0x52, 0x69, 0x8B, 0xA7, 0xAF, 0xA4, 0x94, 0x86, 0x7B, 0x6E,
+    0x5D, 0x51, 0x56, 0x6E, 0x90, 0xA9, 0xAD, 0xA1, 0x91, 0x84, 0x7A, 0x6C, 0x5A, 0x50, 0x59, 0x74,
+    0x96, 0xAC, 0xAC, 0x9E, 0x8E, 0x82, 0x78, 0x6A, 0x58, 0x4E, 0x5A, 0x7A, 0x9B, 0xAE, 0xAB, 0x9B,
+    0x8B, 0x80, 0x75, 0x66, 0x57, 0x52, 0x61, 0x7F, 0x9D, 0xAC, 0xA8, 0x98, 0x89, 0x7E, 0x73, 0x64,
+    0x56, 0x54, 0x65, 0x85, 0xA2, 0xAD, 0xA5, 0x95, 0x87, 0x7D, 0x72, 0x61, 0x53, 0x54, 0x69, 0x8B,
+    0xA6, 0xAE, 0xA3, 0x92, 0x85, 0x7C, 0x6F, 0x5F, 0x52, 0x57, 0x6F, 0x90, 0xA7, 0xAC, 0xA0, 0x90,
+    0x84, 0x79, 0x6C, 0x5D, 0x54, 0x5B, 0x74, 0x93, 0xA8, 0xAA, 0x9D, 0x8D, 0x82, 0x78, 0x6A, 0x5B,
+    0x53, 0x5E, 0x7A, 0x98, 0xAA, 0xA8, 0x99, 0x8B, 0x81, 0x77, 0x69, 0x58, 0x52, 0x61, 0x7F, 0x9D,
+    0xAB, 0xA7, 0x97, 0x89, 0x7E, 0x74, 0x65, 0x58, 0x55, 0x66, 0x84, 0x9F, 0xAA, 0xA3, 0x94, 0x87,
+    0x7D, 0x72, 0x64, 0x57, 0x57, 0x6B, 0x89, 0xA2, 0xAA, 0xA1, 0x91, 0x85, 0x7C, 0x71, 0x61, 0x55,
+    0x59, 0x6F, 0x8F, 0xA6, 0xAA, 0x9F, 0x8F, 0x84, 0x7A, 0x6E, 0x5F, 0x55, 0x5C, 0x74, 0x93, 0xA6,
+    0xA8, 0x9C, 0x8D, 0x81, 0x79, 0x6C, 0x5E, 0x56, 0x60, 0x7A, 0x96, 0xA6, 0xA5, 0x99, 0x8A, 0x80,
+    0x77, 0x69, 0x5B, 0x56, 0x63, 0x7F, 0x9A, 0xA8, 0xA4, 0x95, 0x88, 0x80, 0x75, 0x67, 0x59, 0x56,
+    0x66, 0x84, 0x9E, 0xAA, 0xA2, 0x94, 0x86, 0x7D, 0x73, 0x65, 0x59, 0x59, 0x6B, 0x88, 0xA0, 0xA7,
+    0x9F, 0x91, 0x85, 0x7C, 0x71, 0x63, 0x59, 0x5C, 0x70, 0x8C, 0xA2, 0xA7, 0x9D, 0x8E, 0x84, 0x7A,
+    0x6F, 0x60, 0x57, 0x5E, 0x74, 0x92, 0xA5, 0xA6, 0x9B, 0x8B, 0x82, 0x79, 0x6D, 0x5F, 0x57, 0x60,
+    0x78, 0x95, 0xA6, 0xA5, 0x98, 0x8A, 0x80, 0x77, 0x6B, 0x5D, 0x59, 0x64, 0x7E, 0x98, 0xA5, 0xA2,
+    0x96, 0x89, 0x7F, 0x76, 0x69, 0x5C, 0x59, 0x68, 0x83, 0x9C, 0xA6, 0xA0, 0x93, 0x87, 0x7E, 0x74,
+    0x66, 0x5A, 0x5A, 0x6B, 0x87, 0x9F, 0xA7, 0x9E, 0x90, 0x85, 0x7C, 0x72, 0x65, 0x5A, 0x5D, 0x70,
+    0x8B, 0xA0, 0xA4, 0x9C, 0x8E, 0x83, 0x7B, 0x71, 0x63, 0x5A, 0x60, 0x74, 0x8F, 0xA2, 0xA4, 0x99,
+    0x8B, 0x82, 0x7A, 0x6E, 0x60, 0x59, 0x61, 0x79, 0x94, 0xA4, 0xA3, 0x97, 0x89, 0x81, 0x79, 0x6D,
+    0x5F, 0x5A, 0x65, 0x7D, 0x96, 0xA4, 0xA1, 0x95, 0x88, 0x7F, 0x76, 0x6A, 0x5E, 0x5C, 0x69, 0x82,
+    0x99, 0xA3, 0x9E, 0x92, 0x86, 0x7E, 0x75, 0x68, 0x5D, 0x5C, 0x6C, 0x86, 0x9C, 0xA4, 0x9D, 0x8F,
+    0x85, 0x7D, 0x73, 0x66, 0x5B, 0x5E, 0x70, 0x8B, 0x9F, 0xA4, 0x9B, 0x8D, 0x83, 0x7C, 0x72, 0x65,
+    0x5C, 0x61, 0x74, 0x8E, 0xA0, 0xA2, 0x98, 0x8C, 0x82, 0x7A, 0x70, 0x63, 0x5C, 0x64, 0x79, 0x92,
+    0xA0, 0xA0, 0x96, 0x8A, 0x81, 0x79, 0x6D, 0x61, 0x5C, 0x66, 0x7D, 0x96, 0xA3, 0xA0, 0x93, 0x88,
+    0x80, 0x78, 0x6C, 0x60, 0x5C, 0x69, 0x81, 0x98, 0xA2, 0x9D, 0x91, 0x86, 0x7E, 0x76, 0x6A, 0x5F,
+    0x5F, 0x6D, 0x85, 0x9A, 0xA1, 0x9B, 0x8F, 0x85, 0x7D, 0x73, 0x67, 0x5E, 0x61, 0x71, 0x89, 0x9C,
+    0xA1, 0x99, 0x8C, 0x84, 0x7C, 0x73, 0x66, 0x5D, 0x61, 0x75, 0x8D, 0x9F, 0xA2, 0x97, 0x8A, 0x82,
+    0x7B, 0x71, 0x64, 0x5E, 0x65, 0x78, 0x90, 0x9F, 0x9F, 0x95, 0x89, 0x81, 0x79, 0x6F, 0x64, 0x5F,
+    0x67, 0x7C, 0x93, 0xA0, 0x9D, 0x92, 0x88, 0x80, 0x78, 0x6D, 0x61, 0x5F, 0x6A, 0x81, 0x97, 0xA1,
+    0x9C, 0x90, 0x85, 0x7F, 0x77, 0x6B, 0x60, 0x5F, 0x6D, 0x85, 0x99, 0xA0, 0x9A, 0x8E, 0x85, 0x7E,
+    0x75, 0x6A, 0x60, 0x62, 0x71, 0x88, 0x9A, 0x9F, 0x98, 0x8D, 0x83, 0x7C, 0x73, 0x68, 0x60, 0x64,
+    0x75, 0x8C, 0x9D, 0x9F, 0x96, 0x8B, 0x82, 0x7C, 0x72, 0x66, 0x5F, 0x65, 0x78, 0x90, 0x9F, 0x9E,
+    0x95, 0x89, 0x80, 0x79, 0x70, 0x65, 0x60, 0x69, 0x7D, 0x92, 0x9E, 0x9D, 0x92, 0x87, 0x80, 0x78,
+    0x6E, 0x64, 0x61, 0x6C, 0x81, 0x95, 0x9E, 0x9A, 0x8F, 0x85, 0x7F, 0x77, 0x6D, 0x62, 0x61, 0x6E,
+    0x85, 0x98, 0x9F, 0x99, 0x8D, 0x84, 0x7D, 0x75, 0x6B, 0x61, 0x63, 0x71, 0x87, 0x99, 0x9E, 0x97,
+    0x8C, 0x83, 0x7C, 0x74, 0x69, 0x62, 0x65, 0x75, 0x8A, 0x9A, 0x9D, 0x95, 0x8A, 0x81, 0x7B, 0x72,
+    0x68, 0x61, 0x67, 0x79, 0x8E, 0x9C, 0x9C, 0x93, 0x88, 0x81, 0x7A, 0x70, 0x66, 0x60, 0x68, 0x7C,
+    0x92, 0x9D, 0x9B, 0x91, 0x87, 0x7F, 0x79, 0x70, 0x65, 0x62, 0x6C, 0x80, 0x93, 0x9C, 0x99, 0x8F,
+    0x86, 0x7E, 0x77, 0x6E, 0x65, 0x63, 0x6F, 0x84, 0x95, 0x9C, 0x98, 0x8E, 0x85, 0x7E, 0x76, 0x6C,
+    0x63, 0x65, 0x72, 0x87, 0x98, 0x9D, 0x96, 0x8B, 0x83, 0x7D, 0x75, 0x6A, 0x63, 0x65, 0x75, 0x8A,
+    0x99, 0x9C, 0x94, 0x8A, 0x82, 0x7C, 0x74, 0x69, 0x63, 0x68, 0x79, 0x8D, 0x9A, 0x9A, 0x92, 0x88,
+    0x81, 0x7B, 0x72, 0x68, 0x63, 0x6B, 0x7D, 0x90, 0x9A, 0x99, 0x90, 0x87, 0x80, 0x79, 0x70, 0x66,
+    0x63, 0x6B, 0x7F, 0x93, 0x9C, 0x99, 0x8E, 0x86, 0x7F, 0x78, 0x6F, 0x66, 0x64, 0x6F, 0x83, 0x94,
+    0x9A, 0x96, 0x8D, 0x84, 0x7E, 0x77, 0x6D, 0x65, 0x66, 0x73, 0x86, 0x96, 0x9B, 0x95, 0x8B, 0x83,
+    0x7D, 0x75, 0x6B, 0x64, 0x67, 0x76, 0x89, 0x98, 0x9B, 0x94, 0x89, 0x81, 0x7C, 0x74, 0x6A, 0x64,
+    0x68, 0x78, 0x8C, 0x99, 0x99, 0x91, 0x88, 0x81, 0x7B, 0x72, 0x69, 0x65, 0x6C, 0x7C, 0x8E, 0x99,
+    0x98, 0x8F, 0x86, 0x80, 0x7A, 0x71, 0x68, 0x65, 0x6E, 0x80, 0x91, 0x9A, 0x97, 0x8D, 0x85, 0x7F,
+    0x79, 0x70, 0x67, 0x65, 0x6F, 0x83, 0x93, 0x9B, 0x96, 0x8C, 0x83, 0x7E, 0x78, 0x6F, 0x66, 0x67,
+    0x73, 0x85, 0x95, 0x9A, 0x94, 0x8B, 0x83, 0x7D, 0x76, 0x6D, 0x66, 0x69, 0x76, 0x88, 0x95, 0x99,
+    0x92, 0x89, 0x82, 0x7C, 0x75, 0x6B, 0x66, 0x69, 0x78, 0x8B, 0x97, 0x98, 0x91, 0x87, 0x81, 0x7C,
+    0x74, 0x6A, 0x66, 0x6C, 0x7C, 0x8D, 0x98, 0x97, 0x8F, 0x86, 0x80, 0x7A, 0x73, 0x69, 0x67, 0x6F,
+    0x7F, 0x8F, 0x98, 0x96, 0x8D, 0x85, 0x7F, 0x79, 0x70, 0x69, 0x67, 0x70, 0x82, 0x92, 0x99, 0x95,
+    0x8C, 0x84, 0x7E, 0x78, 0x6F, 0x67, 0x68, 0x73, 0x85, 0x95, 0x99, 0x93, 0x8A, 0x83, 0x7E, 0x77,
+    0x6E, 0x67, 0x69, 0x75, 0x87, 0x95, 0x97, 0x91, 0x89, 0x82, 0x7D, 0x76, 0x6D, 0x68, 0x6B, 0x79,
+    0x89, 0x95, 0x97, 0x90, 0x88, 0x82, 0x7B, 0x74, 0x6B, 0x67, 0x6D, 0x7C, 0x8D, 0x97, 0x96, 0x8F,
+    0x85, 0x80, 0x7B, 0x73, 0x6A, 0x67, 0x6E, 0x7E, 0x8E, 0x97, 0x95, 0x8C, 0x85, 0x7F, 0x7A, 0x72,
+    0x6A, 0x68, 0x71, 0x81, 0x90, 0x96, 0x93, 0x8B, 0x84, 0x7E, 0x78, 0x70, 0x6A, 0x69, 0x74, 0x84,
+    0x92, 0x97, 0x92, 0x89, 0x82, 0x7E, 0x78, 0x6F, 0x68, 0x6A, 0x75, 0x88, 0x94, 0x97, 0x91, 0x88,
+    0x82, 0x7D, 0x76, 0x6E, 0x68, 0x6C, 0x79, 0x89, 0x94, 0x95, 0x8F, 0x87, 0x81, 0x7C, 0x75, 0x6D,
+    0x69, 0x6E, 0x7B, 0x8B, 0x95, 0x95, 0x8D, 0x86, 0x80, 0x7B, 0x74, 0x6C, 0x69, 0x6F, 0x7F, 0x8E,
+    0x96, 0x93, 0x8C, 0x84, 0x7F, 0x7B, 0x73, 0x6A, 0x68, 0x71, 0x81, 0x8F, 0x95, 0x93, 0x8B, 0x84,
+    0x7F, 0x79, 0x72, 0x6A, 0x6A, 0x74, 0x84, 0x91, 0x95, 0x91, 0x89, 0x82, 0x7E, 0x78, 0x70, 0x6A,
+    0x6C, 0x76, 0x87, 0x92, 0x94, 0x8F, 0x88, 0x82, 0x7D, 0x77, 0x6F, 0x69, 0x6D, 0x78, 0x89, 0x94,
+    0x95, 0x8F, 0x86, 0x81, 0x7D, 0x76, 0x6E, 0x6A, 0x6E, 0x7B, 0x8B, 0x94, 0x94, 0x8D, 0x85, 0x80,
+    0x7B, 0x75, 0x6E, 0x6A, 0x70, 0x7E, 0x8C, 0x94, 0x93, 0x8C, 0x85, 0x80, 0x7A, 0x73, 0x6C, 0x6A,
+    0x72, 0x81, 0x8F, 0x95, 0x92, 0x8A, 0x83, 0x7F, 0x7A, 0x72, 0x6C, 0x6B, 0x73, 0x83, 0x90, 0x94,
+    0x90, 0x89, 0x83, 0x7E, 0x79, 0x71, 0x6B, 0x6D, 0x76, 0x85, 0x91, 0x93, 0x8F, 0x88, 0x82, 0x7D,
+    0x77, 0x70, 0x6B, 0x6E, 0x79, 0x87, 0x92, 0x93, 0x8D, 0x86, 0x81, 0x7D, 0x77, 0x6F, 0x6B, 0x6F,
+    0x7C, 0x8A, 0x93, 0x93, 0x8C, 0x85, 0x80, 0x7B, 0x76, 0x6E, 0x6B, 0x71, 0x7D, 0x8C, 0x93, 0x91,
+    0x8B, 0x84, 0x80, 0x7B, 0x74, 0x6D, 0x6C, 0x73, 0x80, 0x8D, 0x93, 0x90, 0x89, 0x83, 0x7F, 0x7A,
+    0x73, 0x6D, 0x6D, 0x75, 0x83, 0x8F, 0x94, 0x90, 0x88, 0x83, 0x7F, 0x7A, 0x72, 0x6C, 0x6C, 0x76,
+    0x85, 0x90, 0x93, 0x8E, 0x88, 0x82, 0x7D, 0x78, 0x71, 0x6C, 0x6E, 0x79, 0x86, 0x90, 0x92, 0x8D,
+    0x86, 0x81, 0x7D, 0x77, 0x70, 0x6C, 0x70, 0x7B, 0x89, 0x91, 0x91, 0x8C, 0x85, 0x81, 0x7C, 0x75,
+    0x6F, 0x6C, 0x70, 0x7D, 0x8B, 0x93, 0x92, 0x8B, 0x84, 0x80, 0x7B, 0x75, 0x6F, 0x6D, 0x73, 0x80,
+    0x8C, 0x92, 0x90, 0x89, 0x83, 0x7E, 0x7A, 0x74, 0x6E, 0x6D, 0x75, 0x82, 0x8E, 0x92, 0x8F, 0x88,
+    0x83, 0x7F, 0x7A, 0x73, 0x6D, 0x6E, 0x77, 0x84, 0x8F, 0x92, 0x8D, 0x87, 0x82, 0x7E, 0x78, 0x72,
+    0x6D, 0x6F, 0x78, 0x86, 0x90, 0x91, 0x8D, 0x85, 0x80, 0x7C, 0x78, 0x71, 0x6D, 0x71, 0x7B, 0x88,
+    0x90, 0x90, 0x8B, 0x85, 0x81, 0x7C, 0x76, 0x70, 0x6D, 0x72, 0x7D, 0x8A, 0x91, 0x90, 0x8A, 0x84,
+    0x7F, 0x7C, 0x75, 0x6F, 0x6D, 0x73, 0x7F, 0x8D, 0x92, 0x8F, 0x89, 0x84, 0x7F, 0x7A, 0x75, 0x6F,
+    0x6E, 0x75, 0x82, 0x8D, 0x91, 0x8F, 0x88, 0x82, 0x7F, 0x7A, 0x74, 0x6E, 0x6F, 0x78, 0x84, 0x8E,
+    0x91, 0x8D, 0x86, 0x81, 0x7D, 0x79, 0x72, 0x6E, 0x70, 0x79, 0x85, 0x8F, 0x91, 0x8C, 0x86, 0x80,
+    0x7E, 0x78, 0x72, 0x6D, 0x70, 0x7B, 0x87, 0x8F, 0x90, 0x8A, 0x84, 0x80, 0x7C, 0x77, 0x71, 0x6F,
+    0x73, 0x7D, 0x89, 0x90, 0x8F, 0x8A, 0x84, 0x7F, 0x7B, 0x76, 0x70, 0x6F, 0x74, 0x80, 0x8B, 0x90,
+    0x8E, 0x88, 0x82, 0x7F, 0x7C, 0x75, 0x70, 0x6E, 0x75, 0x82, 0x8D, 0x91, 0x8E, 0x87, 0x82, 0x7E,
+    0x7A, 0x75, 0x6F, 0x6F, 0x77, 0x83, 0x8D, 0x90, 0x8C, 0x86, 0x81, 0x7E, 0x7A, 0x74, 0x70, 0x71,
+    0x79, 0x85, 0x8E, 0x90, 0x8B, 0x86, 0x81, 0x7D, 0x79, 0x72, 0x6F, 0x72, 0x7B, 0x87, 0x8E, 0x8F,
+    0x8B, 0x84, 0x80, 0x7C, 0x78, 0x72, 0x6E, 0x73, 0x7D, 0x89, 0x8F, 0x8F, 0x89, 0x83, 0x7F, 0x7C,
+    0x77, 0x71, 0x6F, 0x74, 0x7F, 0x89, 0x8F, 0x8E, 0x88, 0x83, 0x7F, 0x7B, 0x76, 0x71, 0x70, 0x76,
+    0x81, 0x8B, 0x8F, 0x8C, 0x87, 0x82, 0x7E, 0x7A, 0x75, 0x70, 0x71, 0x78, 0x83, 0x8D, 0x8F, 0x8B,
+    0x86, 0x81, 0x7E, 0x7A, 0x74, 0x6F, 0x71, 0x7A, 0x85, 0x8D, 0x8F, 0x8B, 0x85, 0x81, 0x7D, 0x79,
+    0x74, 0x70, 0x72, 0x7C, 0x87, 0x8E, 0x8E, 0x8A, 0x84, 0x80, 0x7D, 0x79, 0x72, 0x70, 0x73,
+];
+//SAFETY: QUIET_PLEASE_SAMPLES is in progmem and has exactly that many samples
+pub static QUIET_PLEASE: Clip =
+    unsafe { Clip::from_progmem(core::ptr::addr_of!(QUIET_PLEASE_SAMPLES).cast(), 4687) };
@@ -1,12 +1,22 @@
 ///This is based off of the [SSD1306Ascii Library](https://github.com/greiman/SSD1306Ascii) written in C/C++ for Arduino, but
 /// with most of the fat cut out. We don't need it for this project.
-use arduino_hal::i2c::Error;
+///
+/// The driver only cares about the [embedded_hal::i2c::I2c] trait, so it works with any bus that implements it
+/// (i.e. a mock one on the host) and errors are whatever that bus uses.
+///
+/// Despite the name, it also drives the SH1106 a lot of the cheap 1.3" "SSD1306" modules actually have,
+/// see [Controller].
+use crate::progmem::{self, Slice};
+use core::cmp::{max, min};
+use embedded_graphics::{pixelcolor::BinaryColor, prelude::*};
 use embedded_hal::i2c::{I2c as BaseI2c, Operation, Operation::Write};
 
-///Width of the display. Change this if your display has different dimensions
-const DISPLAY_WIDTH: u8 = 128;
-///Height of the display. Change this if your display has different dimensions
-const DISPLAY_HEIGHT: u8 = 64;
+///Width of the biggest supported panel, RAM buffers are sized for it
+const MAX_WIDTH: u8 = 128;
+///Height of the biggest supported panel
+const MAX_HEIGHT: u8 = 64;
+///Pages of display RAM, both controllers have 64 rows of it no matter how tall the panel is
+const RAM_PAGES: u8 = 8;
 
 /// Set Lower Column Start Address for Page Addressing Mode.
 const SSD1306_SETLOWCOLUMN: u8 = 0x00;
@@ -26,6 +36,8 @@ const SSD1306_SEGREMAP: u8 = 0xA0;
 const SSD1306_DISPLAYALLON_RESUME: u8 = 0xA4;
 /// Set Normal Display.
 const SSD1306_NORMALDISPLAY: u8 = 0xA6;
+/// Set Inverse Display, lit pixels in RAM show up dark and the other way around.
+const SSD1306_INVERTDISPLAY: u8 = 0xA7;
 /// Set Multiplex Ratio from 16 to 63.
 const SSD1306_SETMULTIPLEX: u8 = 0xA8;
 /// Set Display off.
@@ -34,6 +46,8 @@ const SSD1306_DISPLAYOFF: u8 = 0xAE;
 const SSD1306_DISPLAYON: u8 = 0xAF;
 /// Set GDDRAM Page Start Address.
 const SSD1306_SETSTARTPAGE: u8 = 0xB0;
+/// Set COM output scan direction normal.
+const SSD1306_COMSCANINC: u8 = 0xC0;
 /// Set COM output scan direction reversed.
 const SSD1306_COMSCANDEC: u8 = 0xC8;
 /// Set Display Offset.
@@ -46,150 +60,1729 @@ const SSD1306_SETVCOMDETECT: u8 = 0xDB;
 const SSD1306_SETDISPLAYCLOCKDIV: u8 = 0xD5;
 /// Set Pre-charge Period
 const SSD1306_SETPRECHARGE: u8 = 0xD9;
+/// No operation, the SH1106 knows this one too.
+const SSD1306_NOP: u8 = 0xE3;
+/// SH1106 only, DC-DC converter control. Follow with 0x8B enable, 0x8A disable (its take on the charge pump).
+const SH1106_SETDCDC: u8 = 0xAD;
 
-const I2C_ADDR: u8 = 0x3C;
+/// Glyphs [SSD1306Display] remembers being on screen, every one of them takes 10 bytes of RAM.
+/// The readout redraws about a dozen every loop, plus the blanks after its values
+const TEXT_CACHE_SIZE: usize = 16;
+
+/// Bytes of text gathered up on the stack before they go out in one transaction, see [RamBatch].
+/// A full row, so a line of text (21 characters of the default font) is a single transaction. Writing text
+/// still takes less stack than drawing the VU meter does, so it doesn't add to the deepest the stack gets
+const BATCH_SIZE: usize = MAX_WIDTH as usize;
+/// Data slices [SSD1306Display] can send in one transaction, enough to blank the widest row with [BLANK]
+const MAX_PARTS: usize = 8;
+/// Chunk size without batching, what the driver sent before it knew better (Arduino's Wire buffer is 32 bytes)
+const UNBATCHED_CHUNK: usize = 16;
+
+/// Contrast the display starts out with, about half way
+pub const DEFAULT_CONTRAST: u8 = 0x7F;
+
+/// Address most modules ship with, the other one (0x3D) is usually picked with a solder jumper on the back
+pub const DEFAULT_I2C_ADDR: u8 = 0x3C;
+
+/// Panel sizes the driver knows how to set up
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum DisplaySize {
+    Size128x64,
+    Size128x32,
+    Size96x16,
+}
+
+impl DisplaySize {
+    pub const fn width(self) -> u8 {
+        match self {
+            DisplaySize::Size128x64 | DisplaySize::Size128x32 => 128,
+            DisplaySize::Size96x16 => 96,
+        }
+    }
+
+    pub const fn height(self) -> u8 {
+        match self {
+            DisplaySize::Size128x64 => 64,
+            DisplaySize::Size128x32 => 32,
+            DisplaySize::Size96x16 => 16,
+        }
+    }
+
+    /// How panels of this size are usually wired up
+    pub const fn com_pins(self) -> ComPins {
+        match self {
+            DisplaySize::Size128x64 => ComPins::Alternative,
+            DisplaySize::Size128x32 | DisplaySize::Size96x16 => ComPins::Sequential,
+        }
+    }
+}
+
+/// How the panel's rows are wired to the controller's COM pins. Getting this wrong shows up as
+/// every other row missing or the image being stretched out
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum ComPins {
+    Sequential,
+    Alternative,
+    SequentialRemapped,
+    AlternativeRemapped,
+}
+
+impl ComPins {
+    /// Argument byte for [SSD1306_SETCOMPINS]
+    const fn bits(self) -> u8 {
+        match self {
+            ComPins::Sequential => 0x02,
+            ComPins::Alternative => 0x12,
+            ComPins::SequentialRemapped => 0x22,
+            ComPins::AlternativeRemapped => 0x32,
+        }
+    }
+}
+
+/// Display controller chip on the module
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Controller {
+    Ssd1306,
+    /// Has 132 columns of RAM with the panel in the middle of them, and only does page addressing.
+    /// Driving it like an SSD1306 shows everything shifted over by 2 columns (plus garbage on the edge)
+    Sh1106,
+}
+
+impl Controller {
+    /// RAM column the panel's first column is at
+    const fn column_offset(self) -> u8 {
+        match self {
+            Controller::Ssd1306 => 0,
+            Controller::Sh1106 => 2,
+        }
+    }
+}
+
+/// Which side of a field [SSD1306Display::write_field] lines text up on
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Align {
+    Left,
+    Right,
+}
+
+/// Which way up the panel is mounted
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Rotation {
+    /// Header pins on top, like most modules are pictured
+    Normal,
+    UpsideDown,
+}
+
+/// Flips the picture on top of the [Rotation], i.e. for looking at the panel through a mirror or from
+/// behind a diffuser. Mirroring it both ways is the same as turning it upside down
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Mirror {
+    None,
+    /// Left and right swapped
+    Horizontal,
+    /// Top and bottom swapped
+    Vertical,
+}
+
+/// Everything about the panel that can differ between units, see [DisplayConfig::new] for the defaults
+#[derive(Clone, Copy)]
+pub struct DisplayConfig {
+    pub controller: Controller,
+    pub size: DisplaySize,
+    /// 7 bit I2C address, 0x3C or 0x3D
+    pub address: u8,
+    pub rotation: Rotation,
+    pub mirror: Mirror,
+    pub com_pins: ComPins,
+}
+
+impl DisplayConfig {
+    /// Config for a `size` SSD1306 panel at [DEFAULT_I2C_ADDR], mounted normally and wired up the usual way.
+    /// Anything else can be changed with struct update syntax, i.e.
+    /// `DisplayConfig { address: 0x3D, ..DisplayConfig::new(DisplaySize::Size128x32) }`
+    pub const fn new(size: DisplaySize) -> Self {
+        Self {
+            controller: Controller::Ssd1306,
+            size,
+            address: DEFAULT_I2C_ADDR,
+            rotation: Rotation::Normal,
+            mirror: Mirror::None,
+            com_pins: size.com_pins(),
+        }
+    }
+}
+
+impl DisplayConfig {
+    /// Segment remap and COM scan direction commands for the rotation and mirroring.
+    /// Turning it around is just mirroring both the columns and the rows
+    const fn scan_cmds(&self) -> (u8, u8) {
+        let upside_down = matches!(self.rotation, Rotation::UpsideDown);
+        let flip_columns = upside_down != matches!(self.mirror, Mirror::Horizontal);
+        let flip_rows = upside_down != matches!(self.mirror, Mirror::Vertical);
+        //Column 127 mapped to SEG0 and the rows scanned bottom up is the right way around for most modules
+        let seg_remap = if flip_columns {
+            SSD1306_SEGREMAP
+        } else {
+            SSD1306_SEGREMAP | 0x1
+        };
+        let com_scan = if flip_rows {
+            SSD1306_COMSCANINC
+        } else {
+            SSD1306_COMSCANDEC
+        };
+        (seg_remap, com_scan)
+    }
+}
+
+impl Default for DisplayConfig {
+    fn default() -> Self {
+        Self::new(DisplaySize::Size128x64)
+    }
+}
+
+/// Everything that can go wrong talking to the display, `E` is the error type of the I2C bus
+#[derive(Debug)]
+pub enum DisplayError<E> {
+    /// The I2C transaction itself failed, i.e. a loose cable or the display isn't powered
+    Bus(E),
+    /// The cursor would end up off of the display
+    OutOfBounds,
+}
+
+impl<E: ufmt::uDebug> ufmt::uDebug for DisplayError<E> {
+    fn fmt<W: ufmt::uWrite + ?Sized>(
+        &self,
+        f: &mut ufmt::Formatter<'_, W>,
+    ) -> Result<(), W::Error> {
+        match self {
+            DisplayError::Bus(err) => f.debug_tuple("Bus")?.field(err)?.finish(),
+            DisplayError::OutOfBounds => f.write_str("OutOfBounds"),
+        }
+    }
+}
+
+/// [SSD1306Display::write_str] failing partway through, along with how far it got
+#[derive(Debug)]
+pub struct PartialWrite<E> {
+    /// Characters rendered before the error
+    pub written: usize,
+    pub error: DisplayError<E>,
+}
+
+impl<E: ufmt::uDebug> ufmt::uDebug for PartialWrite<E> {
+    fn fmt<W: ufmt::uWrite + ?Sized>(
+        &self,
+        f: &mut ufmt::Formatter<'_, W>,
+    ) -> Result<(), W::Error> {
+        f.debug_struct("PartialWrite")?
+            .field("written", &self.written)?
+            .field("error", &self.error)?
+            .finish()
+    }
+}
 
 pub struct SSD1306Display {
     col: u8,
     row: u8,
+    config: DisplayConfig,
+    /// Terminal-like text, see [SSD1306Display::set_console]
+    console: bool,
+    /// RAM page shown at the top of the panel, moves when scrolling. Rows are always relative to it
+    top_page: u8,
+    font: &'static Font,
+    contrast: u8,
+    inverted: bool,
+    display_on: bool,
+    /// Rows the picture is moved up by, see [SSD1306Display::set_pixel_shift]
+    pixel_shift: u8,
+    /// Where the controller puts the next byte of RAM (RAM page, column), if we know
+    address: Option<(u8, u8)>,
+    text_cache: TextCache,
+    /// See [SSD1306Display::set_batching]
+    batching: bool,
+    /// What the last glyph was scaled up by, see [SSD1306Display::clear_to_eol]
+    scale: u8,
 }
 
 impl SSD1306Display {
-    pub fn new(wire: &mut arduino_hal::I2c) -> Result<Self, Error> {
+    pub fn new<I: BaseI2c>(
+        wire: &mut I,
+        config: DisplayConfig,
+    ) -> Result<Self, DisplayError<I::Error>> {
         //The Command Sequence to send to the display over the wire for initialization
         //
         // This includes the bytes to indicate each value is a command so there is no need
-        // to go through the [SSD1306Display::write_cmd] function with all the error handling.
+        // to go through the [SSD1306Display::write_cmds] function with all the error handling.
         // If we fail here, we should actually abort!
         //
         //References:
         //https://github.com/adafruit/Adafruit_SSD1306/blob/78d6a130b2045a8b7b76616da81603796757ed47/Adafruit_SSD1306.cpp#L565-L621
         //https://github.com/greiman/SSD1306Ascii/blob/c43eaa9db195d17277ffc3d1ec11e3b21ba37775/src/SSD1306init.h#L217-L235
 
+        let multiplex = [config.size.height() - 1];
+        let com_pins = [config.com_pins.bits()];
+        let (seg_remap, com_scan) = config.scan_cmds();
+        let (seg_remap, com_scan) = ([seg_remap], [com_scan]);
+        //The SH1106 has its own command for powering the panel, and is stuck in page mode anyways
+        let (power, memory_mode): (&[u8], &[u8]) = match config.controller {
+            Controller::Ssd1306 => (&[SSD1306_CHARGEPUMP, 0x14], &[SSD1306_MEMORYMODE, 0x02]),
+            Controller::Sh1106 => (&[SH1106_SETDCDC, 0x8B], &[SSD1306_NOP]),
+        };
+
         #[rustfmt::skip]
-        let mut display_init_seq: [Operation;26] = [
+        let mut display_init_seq: [Operation;24] = [
             Write(&[0x00]),
             Write(&[SSD1306_DISPLAYOFF]),
-            Write(&[SSD1306_SETMULTIPLEX]), Write(&[0x3F]),         // ratio 64
+            Write(&[SSD1306_SETMULTIPLEX]), Write(&multiplex),      // ratio = panel height
             Write(&[SSD1306_SETDISPLAYCLOCKDIV]), Write(&[0x80]),   // the suggested ratio 0x80
             Write(&[SSD1306_SETDISPLAYOFFSET]), Write(&[0x0]),      // no offset
             Write(&[SSD1306_SETSTARTLINE]),                         // line #0
-            Write(&[SSD1306_CHARGEPUMP]), Write(&[0x14]),           // internal vcc
-            Write(&[SSD1306_MEMORYMODE]), Write(&[0x02]),           // page mode
-            Write(&[SSD1306_SEGREMAP | 0x1]),                       // column 127 mapped to SEG0
-            Write(&[SSD1306_COMSCANDEC]),                           // column scan direction reversed
-            Write(&[SSD1306_SETCOMPINS]), Write(&[0x12]),           // alt COMWrite(&[pins]), disable remap
-            Write(&[SSD1306_SETCONTRAST]), Write(&[0x7F]),          // contrast level 127
+            Write(power),                                           // internal vcc
+            Write(memory_mode),                                     // page mode
+            Write(&seg_remap),                                      // column 127 mapped to SEG0 (when not rotated)
+            Write(&com_scan),                                       // column scan direction reversed (when not rotated)
+            Write(&[SSD1306_SETCOMPINS]), Write(&com_pins),         // COM pin layout of the panel
+            Write(&[SSD1306_SETCONTRAST]), Write(&[DEFAULT_CONTRAST]), // contrast level 127
             Write(&[SSD1306_SETPRECHARGE]), Write(&[0xF1]),         // pre-charge period (1, 15)
             Write(&[SSD1306_SETVCOMDETECT]), Write(&[0x40]),        // vcomh regulator level
             Write(&[SSD1306_DISPLAYALLON_RESUME]),
             Write(&[SSD1306_NORMALDISPLAY]),
             Write(&[SSD1306_DISPLAYON]),
         ];
-        match wire.transaction(I2C_ADDR, &mut display_init_seq) {
-            Ok(_) => Ok(Self { col: 0, row: 0 }),
-            Err(err) => Err(err),
+        match wire.transaction(config.address, &mut display_init_seq) {
+            Ok(_) => Ok(Self {
+                col: 0,
+                row: 0,
+                config,
+                console: false,
+                top_page: 0,
+                font: &DEFAULT_FONT,
+                contrast: DEFAULT_CONTRAST,
+                inverted: false,
+                display_on: true,
+                pixel_shift: 0,
+                address: None,
+                text_cache: TextCache::new(),
+                batching: true,
+                scale: 1,
+            }),
+            Err(err) => Err(DisplayError::Bus(err)),
+        }
+    }
+    pub fn config(&self) -> &DisplayConfig {
+        &self.config
+    }
+
+    /// Width of the panel in pixels
+    pub fn width(&self) -> u8 {
+        self.config.size.width()
+    }
+
+    /// Height of the panel in pixels, every 8 of them is one page (text row)
+    pub fn height(&self) -> u8 {
+        self.config.size.height()
+    }
+
+    /// Switches fonts for everything written from here on, text already on screen stays as is
+    pub fn set_font(&mut self, font: &'static Font) {
+        self.font = font;
+    }
+    pub fn font(&self) -> &'static Font {
+        self.font
+    }
+
+    /// Turns batching on (the default) or off. With it on, moving the cursor doesn't send anything by itself,
+    /// the commands go out along with the next data in the same transaction, and text is sent a whole run of
+    /// characters at a time. Off sends the cursor on its own and data in small chunks like the driver used to,
+    /// it's only around to compare against (see the firmware's `benchmark` module)
+    pub fn set_batching(&mut self, on: bool) {
+        self.batching = on;
+    }
+    pub fn is_batching(&self) -> bool {
+        self.batching
+    }
+
+    /// Sends `cmds` as commands in a single transaction
+    fn write_cmds<I: BaseI2c>(
+        &mut self,
+        wire: &mut I,
+        cmds: &[u8],
+    ) -> Result<(), DisplayError<I::Error>> {
+        wire.transaction(
+            self.config.address,
+            &mut [Operation::Write(&[0x00]), Operation::Write(cmds)],
+        )
+        .map_err(DisplayError::Bus)
+    }
+
+    /// Sets the brightness, from 0 (dimmest, but still visible) to 255. Lower also means less burn in
+    pub fn set_contrast<I: BaseI2c>(
+        &mut self,
+        wire: &mut I,
+        contrast: u8,
+    ) -> Result<(), DisplayError<I::Error>> {
+        self.write_cmds(wire, &[SSD1306_SETCONTRAST, contrast])?;
+        self.contrast = contrast;
+        Ok(())
+    }
+
+    pub fn contrast(&self) -> u8 {
+        self.contrast
+    }
+    /// Swaps lit and dark pixels for the whole panel, what's in RAM stays the same
+    pub fn set_inverted<I: BaseI2c>(
+        &mut self,
+        wire: &mut I,
+        inverted: bool,
+    ) -> Result<(), DisplayError<I::Error>> {
+        let cmd = if inverted {
+            SSD1306_INVERTDISPLAY
+        } else {
+            SSD1306_NORMALDISPLAY
+        };
+        self.write_cmds(wire, &[cmd])?;
+        self.inverted = inverted;
+        Ok(())
+    }
+    pub fn is_inverted(&self) -> bool {
+        self.inverted
+    }
+
+    /// Turns the panel off (sleep mode, the controller draws next to nothing) or back on.
+    /// RAM is kept while it's off and can still be drawn to, it all shows up once it's back on
+    pub fn set_display_on<I: BaseI2c>(
+        &mut self,
+        wire: &mut I,
+        on: bool,
+    ) -> Result<(), DisplayError<I::Error>> {
+        let cmd = if on {
+            SSD1306_DISPLAYON
+        } else {
+            SSD1306_DISPLAYOFF
+        };
+        self.write_cmds(wire, &[cmd])?;
+        self.display_on = on;
+        Ok(())
+    }
+    pub fn is_display_on(&self) -> bool {
+        self.display_on
+    }
+    /// Turns or mirrors the picture, see [DisplayConfig::rotation] and [DisplayConfig::mirror].
+    /// The controller only applies the column order as data comes in, so whatever's already on screen
+    /// just gets flipped top to bottom and should be redrawn afterwards
+    pub fn set_orientation<I: BaseI2c>(
+        &mut self,
+        wire: &mut I,
+        rotation: Rotation,
+        mirror: Mirror,
+    ) -> Result<(), DisplayError<I::Error>> {
+        let config = DisplayConfig {
+            rotation,
+            mirror,
+            ..self.config
+        };
+        let (seg_remap, com_scan) = config.scan_cmds();
+        self.write_cmds(wire, &[seg_remap, com_scan])?;
+        self.config = config;
+        //Text has to be redrawn to come out the new way around, even where it's the same
+        self.text_cache.clear();
+        Ok(())
+    }
+
+    /// Moves the whole picture up by `rows` pixels (0-7) without redrawing anything, to spread out burn in.
+    /// Whatever moves off of the top comes back in at the bottom on a 64 row panel. Shorter panels
+    /// show the RAM right below them instead, which [SSD1306Display::clear] blanks
+    pub fn set_pixel_shift<I: BaseI2c>(
+        &mut self,
+        wire: &mut I,
+        rows: u8,
+    ) -> Result<(), DisplayError<I::Error>> {
+        if rows >= 8 {
+            return Err(DisplayError::OutOfBounds);
         }
+        self.write_cmds(wire, &[SSD1306_SETDISPLAYOFFSET, rows])?;
+        self.pixel_shift = rows;
+        Ok(())
     }
 
-    pub fn write(&mut self, wire: &mut arduino_hal::I2c, ch: char) -> usize {
-        match ch {
+    pub fn pixel_shift(&self) -> u8 {
+        self.pixel_shift
+    }
+    /// Renders a single character, returns how many characters were rendered.
+    /// Running off of the right edge or the last row is [DisplayError::OutOfBounds], unless in console mode
+    pub fn write<I: BaseI2c>(
+        &mut self,
+        wire: &mut I,
+        ch: char,
+    ) -> Result<usize, DisplayError<I::Error>> {
+        self.write_str(wire, ch.encode_utf8(&mut [0; 4]))
+            .map_err(|partial| partial.error)
+    }
+
+    /// [SSD1306Display::write], with the glyph going into `batch` instead of straight out
+    fn write_batched<I: BaseI2c>(
+        &mut self,
+        wire: &mut I,
+        ch: char,
+        batch: &mut RamBatch,
+    ) -> Result<(), DisplayError<I::Error>> {
+        //Anything still waiting in the batch belongs before whatever the cursor moves to next
+        if matches!(ch, '\r' | '\n') {
+            self.send_batch(wire, batch)?;
+        }
+        let glyph = match ch {
             '\r' => {
                 //Carriage return only sets cursor to beginning of row similar to typewriter (hence why ms-dos uses \r\n)
-                self.col = 0;
-                1
+                return self.set_cursor(wire, 0, self.row);
             }
-            '\n' => {
-                self.set_cursor(wire, 0, self.row + 1).unwrap();    //TODO this is an easy crash we should handle but lazy :p
-                1
+            '\n' => return self.new_line(wire),
+            ch => self.font.glyph(ch),
+        };
+
+        let width = glyph.advance();
+        if self.col as usize + width as usize > self.width() as usize
+            || self.row + self.font.pages() > self.height() / 8
+        {
+            if !self.console {
+                return Err(DisplayError::OutOfBounds);
             }
-            ' ' => {
-                //Handle as a "non-font" space (i.e. not 5px long)
-                //I'm choosing two as a nice choice
-                self.write_ram_buf(wire, &[0x00, 0x00]);
-                self.col += 2;
-                1
+            self.send_batch(wire, batch)?;
+            self.new_line(wire)?;
+        }
+        self.draw_glyph(wire, ch, glyph, 1, batch)
+    }
+
+    /// Draws `glyph` (of `ch` in the current font) at the cursor, `scale` times bigger, by adding it to `batch`.
+    /// Doesn't check if it fits. Nothing gets drawn if the exact same glyph is already there, see [TextCache]
+    fn draw_glyph<I: BaseI2c>(
+        &mut self,
+        wire: &mut I,
+        ch: char,
+        glyph: Glyph,
+        scale: u8,
+        batch: &mut RamBatch,
+    ) -> Result<(), DisplayError<I::Error>> {
+        let (col, row) = (self.col, self.row);
+        let font_pages = self.font.pages();
+        let pages = font_pages * scale;
+        let columns = glyph.advance();
+        let cached = CachedGlyph {
+            font: self.font,
+            ch,
+            col,
+            page: self.ram_page(row),
+            pages,
+            width: columns * scale,
+        };
+
+        if !self.text_cache.contains(&cached) {
+            for page in 0..pages {
+                let ram_page = self.ram_page(row + page);
+                self.text_cache.invalidate(ram_page, col, columns * scale);
+                let mut target = col;
+                for x in 0..columns {
+                    let byte = glyph.scaled_column(x, page, scale, font_pages);
+                    for _ in 0..scale {
+                        self.batch_byte(wire, batch, ram_page, target, byte)?;
+                        target += 1;
+                    }
+                }
             }
-            ch if (ch as u8) > 0x20 && (ch as u8) < 0x80 => {
-                let ascii = ch as u8 - 0x20; //Space is covered in prev branch
-                let byte_seq = FONT_DATA[ascii as usize];
-                //In theory, this should just work as is given we have no significant modification
-                self.write_ram_buf(wire, &byte_seq);
-                self.col += 6; //Add a little buffer space
-                1
+            //Counts as on screen already, if sending the batch fails the cache gets cleared anyways
+            self.text_cache.insert(cached);
+            if !self.batching {
+                self.send_batch(wire, batch)?;
             }
-            _ => 0, //Just ignore the character
         }
+
+        //Back up to the top page for the next character
+        self.col = col + columns * scale;
+        self.row = row;
+        self.scale = scale;
+        Ok(())
     }
 
-    pub fn write_str(&mut self, wire: &mut arduino_hal::I2c, str: &str) -> usize {
-        let mut count = 0;
-        for ch in str.chars() {
-            count += self.write(wire, ch);
+    /// Turns terminal-like text on or off. When on, [SSD1306Display::write] wraps at the right edge
+    /// instead of erroring, and a new line on the last row scrolls everything up with
+    /// [SSD1306Display::scroll]. Handy for dumping log messages on the screen
+    pub fn set_console(&mut self, on: bool) {
+        self.console = on;
+    }
+
+    fn new_line<I: BaseI2c>(&mut self, wire: &mut I) -> Result<(), DisplayError<I::Error>> {
+        let pages = self.font.pages();
+        let rows = self.height() / 8;
+        if self.console && self.row + 2 * pages > rows {
+            //Scroll just far enough for the next line to fit under this one
+            for _ in 0..self.row + 2 * pages - rows {
+                self.scroll(wire)?;
+            }
+            self.set_cursor(wire, 0, rows.saturating_sub(pages))
+        } else {
+            self.set_cursor(wire, 0, self.row + pages)
         }
-        count
     }
 
-    fn write_ram_buf(&mut self, wire: &mut arduino_hal::I2c, bytes: &[u8]) {
-        //This is "optimized" since the buffer mode allows for 16 bytes to be processed in a single command
-        bytes.chunks(16).for_each(|chunk| {
-            match wire.transaction(
-                I2C_ADDR,
-                &mut [Operation::Write(&[0x40]), Operation::Write(chunk)],
-            ) {
-                Ok(_) => (),
-                Err(_) => (), //TODO Better err handling!!!
+    /// Moves everything on screen up a row and puts the cursor at the start of the (now blank) last row.
+    ///
+    /// This is done in hardware by moving the display start line, so nothing has to be redrawn and it
+    /// only costs sending a single row. Rows passed to everything else stay relative to the top of the
+    /// panel, so it doesn't have to know about scrolling
+    pub fn scroll<I: BaseI2c>(&mut self, wire: &mut I) -> Result<(), DisplayError<I::Error>> {
+        //Blank the row before it comes into view, it's whatever was scrolled off the top last time around
+        let last_row = self.height() / 8 - 1;
+        self.top_page = (self.top_page + 1) % RAM_PAGES;
+        self.write_blank(wire, self.ram_page(last_row), 0, self.width())?;
+
+        self.write_cmds(wire, &[SSD1306_SETSTARTLINE | (self.top_page * 8)])?;
+        self.set_cursor(wire, 0, last_row)
+    }
+
+    /// Renders `str` starting at the cursor. On failure, the error says how many characters made it
+    pub fn write_str<I: BaseI2c>(
+        &mut self,
+        wire: &mut I,
+        str: &str,
+    ) -> Result<usize, PartialWrite<I::Error>> {
+        self.write_chars(wire, str, None)
+    }
+    /// Renders `ch` `scale` times bigger (1-8), spanning `scale` times as many pages down from the cursor's page.
+    /// The cursor stays on the top page, so scaled text lines up along its top edge
+    pub fn write_scaled<I: BaseI2c>(
+        &mut self,
+        wire: &mut I,
+        ch: char,
+        scale: u8,
+    ) -> Result<usize, DisplayError<I::Error>> {
+        self.write_str_scaled(wire, ch.encode_utf8(&mut [0; 4]), scale)
+            .map_err(|partial| partial.error)
+    }
+
+    /// [SSD1306Display::write_scaled], with the glyph going into `batch` instead of straight out
+    fn write_scaled_batched<I: BaseI2c>(
+        &mut self,
+        wire: &mut I,
+        ch: char,
+        scale: u8,
+        batch: &mut RamBatch,
+    ) -> Result<(), DisplayError<I::Error>> {
+        if matches!(ch, '\r' | '\n') {
+            self.send_batch(wire, batch)?;
+        }
+        let glyph = match ch {
+            '\r' => return self.set_cursor(wire, 0, self.row),
+            '\n' => return self.set_cursor(wire, 0, self.row + self.font.pages() * scale),
+            ch => self.font.glyph(ch),
+        };
+
+        let width = glyph.advance() as usize * scale as usize;
+        if !(1..=8).contains(&scale)
+            || self.row as usize + self.font.pages() as usize * scale as usize
+                > self.height() as usize / 8
+            || self.col as usize + width > self.width() as usize
+        {
+            return Err(DisplayError::OutOfBounds);
+        }
+
+        self.draw_glyph(wire, ch, glyph, scale, batch)
+    }
+
+    /// [SSD1306Display::write_str], but scaled up like [SSD1306Display::write_scaled]
+    pub fn write_str_scaled<I: BaseI2c>(
+        &mut self,
+        wire: &mut I,
+        str: &str,
+        scale: u8,
+    ) -> Result<usize, PartialWrite<I::Error>> {
+        self.write_chars(wire, str, Some(scale))
+    }
+
+    /// Renders `str` like [SSD1306Display::write_str], or like [SSD1306Display::write_str_scaled] with a `scale`.
+    /// Characters get batched up so a line of text only takes a couple of transactions
+    fn write_chars<I: BaseI2c>(
+        &mut self,
+        wire: &mut I,
+        str: &str,
+        scale: Option<u8>,
+    ) -> Result<usize, PartialWrite<I::Error>> {
+        let mut batch = RamBatch::new();
+        let mut written = 0;
+        //Characters that are definitely on screen, the last few could still be waiting in the batch
+        let mut sent = 0;
+        for ch in str.chars() {
+            let result = match scale {
+                None => self.write_batched(wire, ch, &mut batch),
+                Some(scale) => self.write_scaled_batched(wire, ch, scale, &mut batch),
+            };
+            match result {
+                Ok(()) => written += 1,
+                //Everything before it still has to go out
+                Err(DisplayError::OutOfBounds) => {
+                    return match self.send_batch(wire, &mut batch) {
+                        Ok(()) => Err(PartialWrite {
+                            written,
+                            error: DisplayError::OutOfBounds,
+                        }),
+                        Err(error) => Err(PartialWrite {
+                            written: sent,
+                            error,
+                        }),
+                    };
+                }
+                Err(error) => {
+                    return Err(PartialWrite {
+                        written: sent,
+                        error,
+                    })
+                }
             }
-        });
+            if batch.len == 0 {
+                sent = written;
+            }
+        }
+        self.send_batch(wire, &mut batch)
+            .map_err(|error| PartialWrite {
+                written: sent,
+                error,
+            })?;
+        Ok(written)
+    }
+
+    /// Bundles the display with its bus so [ufmt::uwrite] can render straight to the screen,
+    /// without formatting into a buffer first
+    pub fn writer<'a, I: BaseI2c>(&'a mut self, wire: &'a mut I) -> DisplayWriter<'a, I> {
+        DisplayWriter {
+            display: self,
+            wire,
+            scale: 1,
+        }
     }
 
-    pub fn clear(&mut self, wire: &mut arduino_hal::I2c) -> Result<(), Error> {
-        for row in 0..DISPLAY_HEIGHT {
-            self.set_cursor(wire, 0, row)?;
-            for _ in 0..DISPLAY_WIDTH / 16 {
-                self.write_ram_buf(wire, &[0x00; 16]);
+    /// Sends `parts` back to back as GDDRAM data for RAM page `ram_page` starting at panel column `col`, in a
+    /// single transaction. Doesn't touch the cursor, and keeping the [TextCache] up to date is up to the caller
+    /// unless it fails
+    fn send_ram<I: BaseI2c>(
+        &mut self,
+        wire: &mut I,
+        ram_page: u8,
+        col: u8,
+        parts: &[&[u8]],
+    ) -> Result<(), DisplayError<I::Error>> {
+        if !self.batching && self.address != Some((ram_page, col)) {
+            self.set_ram_address(wire, ram_page, col)?;
+        }
+
+        //With the Co bit (0x80) set, a control byte only covers the one command after it, so moving the
+        // controller there fits in front of the data. The SH1106 reads it the same way
+        let ram_col = col + self.config.controller.column_offset();
+        let header = [
+            0x80,
+            SSD1306_SETSTARTPAGE | ram_page,
+            0x80,
+            SSD1306_SETLOWCOLUMN | (ram_col & 0xF),
+            0x80,
+            SSD1306_SETHIGHCOLUMN | (ram_col >> 4),
+            0x40,
+        ];
+        //The controller is usually already there when writing out a line
+        let header = if self.address == Some((ram_page, col)) {
+            &header[6..]
+        } else {
+            &header[..]
+        };
+        let mut ops: [Operation; 1 + MAX_PARTS] = core::array::from_fn(|i| match i {
+            0 => Write(header),
+            i => Write(parts.get(i - 1).copied().unwrap_or(&[])),
+        });
+        let len: usize = parts.iter().map(|part| part.len()).sum();
+
+        self.address = None;
+        match wire.transaction(self.config.address, &mut ops[..=parts.len()]) {
+            Ok(()) => {
+                self.address = Some((ram_page, col + len as u8));
+                Ok(())
             }
+            Err(err) => {
+                //Could have made it partway, so whatever text was there can't be trusted anymore
+                self.text_cache.clear();
+                Err(DisplayError::Bus(err))
+            }
+        }
+    }
+
+    /// Writes `bytes` to RAM page `ram_page` starting at column `col`, all in one transaction when batching.
+    /// Doesn't touch the cursor
+    fn write_ram<I: BaseI2c>(
+        &mut self,
+        wire: &mut I,
+        ram_page: u8,
+        col: u8,
+        bytes: &[u8],
+    ) -> Result<(), DisplayError<I::Error>> {
+        //Whatever text was there is about to be gone
+        self.text_cache.invalidate(ram_page, col, bytes.len() as u8);
+        let chunk_size = if self.batching {
+            max(bytes.len(), 1)
+        } else {
+            UNBATCHED_CHUNK
+        };
+        let mut col = col;
+        for chunk in bytes.chunks(chunk_size) {
+            self.send_ram(wire, ram_page, col, &[chunk])?;
+            col += chunk.len() as u8;
+        }
+        Ok(())
+    }
+
+    /// Blanks `count` columns (up to a full row) of RAM page `ram_page` starting at column `col`,
+    /// doesn't touch the cursor
+    fn write_blank<I: BaseI2c>(
+        &mut self,
+        wire: &mut I,
+        ram_page: u8,
+        col: u8,
+        count: u8,
+    ) -> Result<(), DisplayError<I::Error>> {
+        self.text_cache.invalidate(ram_page, col, count);
+        //The same zeros over and over instead of a row's worth of them in RAM
+        let mut parts: [&[u8]; MAX_PARTS] = [&[]; MAX_PARTS];
+        let mut used = 0;
+        let mut left = min(count, MAX_WIDTH) as usize;
+        while left > 0 {
+            let len = min(left, BLANK.len());
+            parts[used] = &BLANK[..len];
+            used += 1;
+            left -= len;
+        }
+
+        if self.batching {
+            return self.send_ram(wire, ram_page, col, &parts[..used]);
+        }
+        let mut col = col;
+        for part in &parts[..used] {
+            self.send_ram(wire, ram_page, col, &[part])?;
+            col += part.len() as u8;
+        }
+        Ok(())
+    }
+
+    /// Adds `byte` to `batch` for column `col` of RAM page `ram_page`, sending what's already in there first
+    /// if it doesn't carry on right where that left off or there's no room left
+    fn batch_byte<I: BaseI2c>(
+        &mut self,
+        wire: &mut I,
+        batch: &mut RamBatch,
+        ram_page: u8,
+        col: u8,
+        byte: u8,
+    ) -> Result<(), DisplayError<I::Error>> {
+        let size = if self.batching {
+            BATCH_SIZE
+        } else {
+            UNBATCHED_CHUNK
+        };
+        let len = batch.len as usize;
+        if len > 0 && (batch.page != ram_page || batch.col + batch.len != col || len == size) {
+            self.send_batch(wire, batch)?;
+        }
+        if batch.len == 0 {
+            batch.page = ram_page;
+            batch.col = col;
+        }
+        batch.data[batch.len as usize] = byte;
+        batch.len += 1;
+        Ok(())
+    }
+
+    /// Sends whatever is waiting in `batch` and empties it
+    fn send_batch<I: BaseI2c>(
+        &mut self,
+        wire: &mut I,
+        batch: &mut RamBatch,
+    ) -> Result<(), DisplayError<I::Error>> {
+        if batch.len == 0 {
+            return Ok(());
+        }
+        let len = batch.len as usize;
+        batch.len = 0;
+        self.send_ram(wire, batch.page, batch.col, &[&batch.data[..len]])
+    }
+
+    pub fn clear<I: BaseI2c>(&mut self, wire: &mut I) -> Result<(), DisplayError<I::Error>> {
+        let rows = self.height() / 8;
+        //Page right below a short panel comes into view with a pixel shift, so that gets blanked too
+        let pages = min(rows + 1, RAM_PAGES);
+        for row in 0..pages {
+            self.write_blank(wire, self.ram_page(row), 0, self.width())?;
         }
 
         self.set_cursor(wire, 0, 0)
     }
 
-    pub fn set_cursor(
+    /// Blanks everything from the cursor up to the right edge of its row, the cursor stays put.
+    /// Writing a value and then clearing the rest of the line leaves no leftovers when it gets shorter.
+    /// That's as many pages down as the last character written took up, with a tall font or scaled up text
+    pub fn clear_to_eol<I: BaseI2c>(&mut self, wire: &mut I) -> Result<(), DisplayError<I::Error>> {
+        let col = self.col;
+        let pages = min(self.font.pages() * self.scale, self.height() / 8 - self.row);
+        self.clear_to(wire, self.width(), pages)?;
+        self.col = col;
+        Ok(())
+    }
+
+    /// Blanks from the cursor up to (not including) column `end`, on `pages` rows down from the cursor's.
+    /// The cursor ends up at `end` on the top row, like it would after writing that far (i.e. with
+    /// [SSD1306Display::write_scaled], to get rid of the rest of a scaled up value)
+    pub fn clear_to<I: BaseI2c>(
         &mut self,
-        wire: &mut arduino_hal::I2c,
+        wire: &mut I,
+        end: u8,
+        pages: u8,
+    ) -> Result<(), DisplayError<I::Error>> {
+        let (col, row) = (self.col, self.row);
+        if end > self.width() || row + pages > self.height() / 8 {
+            return Err(DisplayError::OutOfBounds);
+        }
+        if col >= end {
+            return Ok(());
+        }
+
+        //Blanks get remembered like text, so clearing behind a value that didn't change costs nothing
+        let blank = self.blank_area(end, pages);
+        if !self.text_cache.contains(&blank) {
+            for page in 0..pages {
+                self.write_blank(wire, self.ram_page(row + page), col, end - col)?;
+            }
+            self.text_cache.insert(blank);
+        }
+        self.row = row;
+        self.col = end;
+        Ok(())
+    }
+    /// Renders `str` in a field `width` pixels wide starting at the cursor, lined up to the left or right
+    /// side of it. The rest of the field gets blanked, so a value can be updated in place without
+    /// leaving pixels of a longer previous value behind. Text that doesn't fit is [DisplayError::OutOfBounds]
+    /// without anything being drawn. The cursor ends up right after the field
+    pub fn write_field<I: BaseI2c>(
+        &mut self,
+        wire: &mut I,
+        str: &str,
+        width: u8,
+        align: Align,
+    ) -> Result<usize, PartialWrite<I::Error>> {
+        let fail = |error| PartialWrite { written: 0, error };
+        let text = self.font.text_width(str);
+        let field_end = self.col as u16 + width as u16;
+        let pages = self.font.pages();
+        if text > width as u16
+            || field_end > self.width() as u16
+            || self.row + pages > self.height() / 8
+        {
+            return Err(fail(DisplayError::OutOfBounds));
+        }
+
+        //Padding and text all go in the same batch, so a narrow field is a single transaction
+        let mut batch = RamBatch::new();
+        if align == Align::Right {
+            self.blank_batched(wire, self.col + width - text as u8, pages, &mut batch)
+                .map_err(fail)?;
+        }
+        let mut written = 0;
+        let mut sent = 0;
+        //Line breaks would move us out of the field
+        for ch in str.chars().filter(|&ch| ch != '\r' && ch != '\n') {
+            self.write_batched(wire, ch, &mut batch)
+                .map_err(|error| PartialWrite {
+                    written: sent,
+                    error,
+                })?;
+            written += 1;
+            if batch.len == 0 {
+                sent = written;
+            }
+        }
+        if align == Align::Left {
+            self.blank_batched(wire, field_end as u8, pages, &mut batch)
+                .map_err(|error| PartialWrite {
+                    written: sent,
+                    error,
+                })?;
+        }
+        self.send_batch(wire, &mut batch)
+            .map_err(|error| PartialWrite {
+                written: sent,
+                error,
+            })?;
+        Ok(written)
+    }
+
+    /// [SSD1306Display::clear_to], with the blank columns going into `batch`. Doesn't check bounds
+    fn blank_batched<I: BaseI2c>(
+        &mut self,
+        wire: &mut I,
+        end: u8,
+        pages: u8,
+        batch: &mut RamBatch,
+    ) -> Result<(), DisplayError<I::Error>> {
+        let (col, row) = (self.col, self.row);
+        let blank = self.blank_area(end, pages);
+        if col < end && !self.text_cache.contains(&blank) {
+            for page in 0..pages {
+                let ram_page = self.ram_page(row + page);
+                self.text_cache.invalidate(ram_page, col, end - col);
+                for x in col..end {
+                    self.batch_byte(wire, batch, ram_page, x, 0x00)?;
+                }
+            }
+            self.text_cache.insert(blank);
+        }
+        self.col = max(col, end);
+        Ok(())
+    }
+
+    /// How the blank area from the cursor up to column `end`, `pages` down, is remembered in the [TextCache]
+    fn blank_area(&self, end: u8, pages: u8) -> CachedGlyph {
+        CachedGlyph {
+            font: core::ptr::null(),
+            ch: ' ',
+            col: self.col,
+            page: self.ram_page(self.row),
+            pages,
+            width: end.saturating_sub(self.col),
+        }
+    }
+
+    /// Moves the cursor to pixel column `col` of page `row`, erroring if either is off of the display.
+    /// When batching, nothing gets sent until something is drawn there
+    pub fn set_cursor<I: BaseI2c>(
+        &mut self,
+        wire: &mut I,
         col: u8,
         row: u8,
-    ) -> Result<(), Error> {
-        //Set row
-        if row < DISPLAY_HEIGHT / 8 {
-            self.row = row;
-            wire.transaction(
-                I2C_ADDR,
-                &mut [
-                    Operation::Write(&[0x00]),
-                    Operation::Write(&[SSD1306_SETSTARTPAGE | row]),
-                ],
-            )?;
+    ) -> Result<(), DisplayError<I::Error>> {
+        if row >= self.height() / 8 || col >= self.width() {
+            return Err(DisplayError::OutOfBounds);
+        }
+
+        self.row = row;
+        self.col = col;
+        if self.batching {
+            return Ok(());
         }
+        self.set_ram_address(wire, self.ram_page(row), col)
+    }
+
+    /// RAM page shown on page `row` of the panel
+    fn ram_page(&self, row: u8) -> u8 {
+        (self.top_page + row) % RAM_PAGES
+    }
+
+    /// Points the hardware at RAM page `ram_page` and panel column `col`, without any bounds checks
+    /// or touching the cursor
+    fn set_ram_address<I: BaseI2c>(
+        &mut self,
+        wire: &mut I,
+        ram_page: u8,
+        col: u8,
+    ) -> Result<(), DisplayError<I::Error>> {
+        //Only known again once both made it over
+        self.address = None;
+        //Set row
+        wire.transaction(
+            self.config.address,
+            &mut [
+                Operation::Write(&[0x00]),
+                Operation::Write(&[SSD1306_SETSTARTPAGE | ram_page]),
+            ],
+        )
+        .map_err(DisplayError::Bus)?;
         //Set col
-        if col < DISPLAY_WIDTH {
-            self.col = col;
-            wire.transaction(
-                I2C_ADDR,
-                &mut [
-                    Operation::Write(&[0x00]),
-                    Operation::Write(&[SSD1306_SETLOWCOLUMN | (col & 0xF)]),
-                    Operation::Write(&[SSD1306_SETHIGHCOLUMN | (col >> 4)]),
-                ],
-            )?;
+        let ram_col = col + self.config.controller.column_offset();
+        wire.transaction(
+            self.config.address,
+            &mut [
+                Operation::Write(&[0x00]),
+                Operation::Write(&[SSD1306_SETLOWCOLUMN | (ram_col & 0xF)]),
+                Operation::Write(&[SSD1306_SETHIGHCOLUMN | (ram_col >> 4)]),
+            ],
+        )
+        .map_err(DisplayError::Bus)?;
+        self.address = Some((ram_page, col));
+        Ok(())
+    }
+
+    /// Writes raw GDDRAM bytes to page `row` starting at column `col`, every byte is one column of 8 pixels
+    /// (LSB on top). Handy for drawing graphics a page at a time without needing a [FrameBuffer]
+    pub fn write_columns<I: BaseI2c>(
+        &mut self,
+        wire: &mut I,
+        col: u8,
+        row: u8,
+        bytes: &[u8],
+    ) -> Result<(), DisplayError<I::Error>> {
+        if col as usize + bytes.len() > self.width() as usize {
+            return Err(DisplayError::OutOfBounds);
+        }
+        self.set_cursor(wire, col, row)?;
+        self.write_ram(wire, self.ram_page(row), col, bytes)?;
+        self.col += bytes.len() as u8;
+        Ok(())
+    }
+
+    /// Draws `bitmap` with its top left corner at pixel (`x`, `y`), which doesn't have to line up with a page.
+    ///
+    /// The display RAM can't be read back over I2C, so the rest of the pages the bitmap only partly covers
+    /// (right above and below it, within its columns) gets blanked. Draw it into a [FrameBuffer] with
+    /// [FrameBuffer::draw_bitmap] if that matters. Nothing gets drawn if it doesn't fit on the display
+    pub fn draw_bitmap<I: BaseI2c>(
+        &mut self,
+        wire: &mut I,
+        x: u8,
+        y: u8,
+        bitmap: &Bitmap,
+    ) -> Result<(), DisplayError<I::Error>> {
+        if bitmap.width == 0 || bitmap.height == 0 {
+            return Ok(());
+        }
+        if x as u16 + bitmap.width as u16 > self.width() as u16
+            || y as u16 + bitmap.height as u16 > self.height() as u16
+        {
+            return Err(DisplayError::OutOfBounds);
+        }
+
+        //Every page of the bitmap straddles two pages of the display, unless it happens to line up
+        let shift = y % 8;
+        let first_page = y / 8;
+        let last_page = (y + bitmap.height - 1) / 8;
+        for page in first_page..=last_page {
+            let below = page - first_page; //Bitmap page whose top part lands here
+            self.set_cursor(wire, x, page)?;
+            let ram_page = self.ram_page(page);
+            self.text_cache.invalidate(ram_page, x, bitmap.width);
+            let mut batch = RamBatch::new();
+            for col in 0..bitmap.width {
+                let mut byte = if below < bitmap.pages() {
+                    bitmap.column(col, below) << shift
+                } else {
+                    0x00
+                };
+                if shift > 0 && below > 0 {
+                    byte |= bitmap.column(col, below - 1) >> (8 - shift);
+                }
+                self.batch_byte(wire, &mut batch, ram_page, x + col, byte)?;
+            }
+            self.send_batch(wire, &mut batch)?;
+            self.col += bitmap.width;
+        }
+        Ok(())
+    }
+
+    /// Sends whatever changed in `buffer` since the last flush, one transaction per dirty page.
+    /// The text cursor ends up after the last region that was sent
+    pub fn flush<I: BaseI2c, const PAGES: usize>(
+        &mut self,
+        wire: &mut I,
+        buffer: &mut FrameBuffer<PAGES>,
+    ) -> Result<(), DisplayError<I::Error>> {
+        for (i, page) in buffer.pages.iter().enumerate() {
+            if let Some((start, end)) = buffer.dirty[i] {
+                //Buffers are sized for the biggest panel, whatever is past the edge of this one gets dropped
+                let end = min(end, self.width() - 1);
+                if start <= end {
+                    self.set_cursor(wire, start, buffer.first_page + i as u8)?;
+                    let ram_page = self.ram_page(self.row);
+                    self.write_ram(wire, ram_page, start, &page[start as usize..=end as usize])?;
+                    self.col = end.saturating_add(1);
+                }
+                //Only mark it clean once it's actually made it over, so a failed flush gets retried
+                buffer.dirty[i] = None;
+            }
+        }
+        Ok(())
+    }
+    /// Draws a whole screen one page at a time, so it only ever needs 128 bytes of RAM instead of a full
+    /// [FrameBuffer]. `draw` gets called once per page and has to draw the full scene every time,
+    /// only whatever lands on the current page is kept. Everything not drawn ends up blank
+    pub fn draw_paged<I: BaseI2c>(
+        &mut self,
+        wire: &mut I,
+        mut draw: impl FnMut(&mut FrameBuffer<1>),
+    ) -> Result<(), DisplayError<I::Error>> {
+        for page in 0..self.height() / 8 {
+            let mut buffer = FrameBuffer::new(page);
+            draw(&mut buffer);
+            //Whole page goes out, the blank parts have to overwrite whatever was there before
+            buffer.mark_dirty(0, 0, self.width() - 1);
+            self.flush(wire, &mut buffer)?;
+        }
+        Ok(())
+    }
+}
+
+/// [SSD1306Display] along with the bus it's on, from [SSD1306Display::writer]
+pub struct DisplayWriter<'a, I> {
+    display: &'a mut SSD1306Display,
+    wire: &'a mut I,
+    scale: u8,
+}
+
+impl<I> DisplayWriter<'_, I> {
+    /// Renders everything `scale` times bigger, like [SSD1306Display::write_scaled]
+    pub fn scaled(mut self, scale: u8) -> Self {
+        self.scale = scale;
+        self
+    }
+}
+
+impl<I: BaseI2c> ufmt::uWrite for DisplayWriter<'_, I> {
+    type Error = DisplayError<I::Error>;
+
+    fn write_str(&mut self, s: &str) -> Result<(), Self::Error> {
+        let written = if self.scale == 1 {
+            self.display.write_str(self.wire, s)
+        } else {
+            self.display.write_str_scaled(self.wire, s, self.scale)
+        };
+        written.map(|_| ()).map_err(|partial| partial.error)
+    }
+}
+
+/// GDDRAM bytes headed for consecutive columns of one RAM page, gathered up on the stack so a run of
+/// characters goes out in a single transaction instead of one per character
+struct RamBatch {
+    data: [u8; BATCH_SIZE],
+    len: u8,
+    /// Where `data` goes (RAM page, column)
+    page: u8,
+    col: u8,
+}
+
+impl RamBatch {
+    const fn new() -> Self {
+        Self {
+            data: [0x00; BATCH_SIZE],
+            len: 0,
+            page: 0,
+            col: 0,
+        }
+    }
+}
+
+/// Glyph that was drawn on screen, everything needed to tell if drawing it again would change anything
+#[derive(Clone, Copy, PartialEq, Eq)]
+struct CachedGlyph {
+    /// Null for a blanked out area, see [SSD1306Display::clear_to]
+    font: *const Font,
+    ch: char,
+    col: u8,
+    /// Top RAM page, rather than the row, so it stays put when scrolling
+    page: u8,
+    pages: u8,
+    width: u8,
+}
+
+/// What text is where on screen, so [SSD1306Display] can skip redrawing a value that didn't change
+/// (i.e. all but the last digit of a reading). Every write to display RAM drops the glyphs it touches,
+/// so only what's really still there is remembered. When full, the oldest glyph is forgotten
+struct TextCache {
+    glyphs: [Option<CachedGlyph>; TEXT_CACHE_SIZE],
+    /// Slot to reuse when there are no empty ones
+    oldest: u8,
+}
+
+impl TextCache {
+    const fn new() -> Self {
+        Self {
+            glyphs: [None; TEXT_CACHE_SIZE],
+            oldest: 0,
+        }
+    }
+
+    fn contains(&self, glyph: &CachedGlyph) -> bool {
+        self.glyphs.contains(&Some(*glyph))
+    }
+
+    fn insert(&mut self, glyph: CachedGlyph) {
+        let slot = match self.glyphs.iter().position(Option::is_none) {
+            Some(slot) => slot,
+            None => {
+                let slot = self.oldest as usize;
+                self.oldest = ((slot + 1) % TEXT_CACHE_SIZE) as u8;
+                slot
+            }
+        };
+        self.glyphs[slot] = Some(glyph);
+    }
+
+    /// Forgets every glyph that overlaps `len` columns of RAM page `page` starting at `col`
+    fn invalidate(&mut self, page: u8, col: u8, len: u8) {
+        let end = col as u16 + len as u16;
+        for slot in &mut self.glyphs {
+            if let Some(glyph) = slot {
+                let on_page = (page + RAM_PAGES - glyph.page) % RAM_PAGES < glyph.pages;
+                let overlaps = (col as u16) < glyph.col as u16 + glyph.width as u16
+                    && (glyph.col as u16) < end;
+                if on_page && overlaps {
+                    *slot = None;
+                }
+            }
+        }
+    }
+
+    fn clear(&mut self) {
+        self.glyphs = [None; TEXT_CACHE_SIZE];
+    }
+}
+
+/// Monochrome image stored in flash, in the display's own layout: column bytes (LSB on top) for every
+/// 8 rows, one page after the other. Use `tools/img2bitmap.py` to convert a PNG or PBM into one
+/// (see the firmware's `bitmaps` module)
+pub struct Bitmap {
+    width: u8,
+    height: u8,
+    data: *const u8,
+}
+
+//SAFETY: Bitmaps only point into flash, which is never written to
+unsafe impl Sync for Bitmap {}
+
+impl Bitmap {
+    /// # Safety
+    /// `data` must point to `width * ceil(height / 8)` bytes in a static placed in `.progmem.data`
+    pub const unsafe fn from_progmem(width: u8, height: u8, data: *const u8) -> Self {
+        Self {
+            width,
+            height,
+            data,
+        }
+    }
+
+    pub fn width(&self) -> u8 {
+        self.width
+    }
+
+    pub fn height(&self) -> u8 {
+        self.height
+    }
+
+    fn pages(&self) -> u8 {
+        self.height.div_ceil(8)
+    }
+
+    /// Column `x` of page `page` of the bitmap, the converter leaves rows past the bottom blank
+    fn column(&self, x: u8, page: u8) -> u8 {
+        let index = page as usize * self.width as usize + x as usize;
+        //SAFETY: Callers stay inside of the bitmap, which is in progmem as promised by [Bitmap::from_progmem]
+        unsafe { progmem::read_byte(self.data.add(index)) }
+    }
+}
+
+/// RAM copy of `PAGES` pages of the display starting at `first_page`, for pixel level drawing.
+///
+/// A full screen buffer (8 pages) is 1KB, half of our SRAM, so it can also just cover the part of the
+/// screen that actually needs it. Changes are tracked as a dirty column range per page, and only those
+/// get sent on [SSD1306Display::flush]. Pages are always as wide as the widest panel, on a narrower
+/// one whatever is past its edge just never gets sent.
+///
+/// This is also why [embedded_graphics] draws into one of these (or [SSD1306Display::draw_paged]) and
+/// [SSD1306Display] isn't a `DrawTarget` itself. Every byte of display RAM holds a column of 8 pixels, and
+/// the controller can't be read from over I2C, so changing a single pixel without wiping out the other 7
+/// needs a copy of what's there already.
+pub struct FrameBuffer<const PAGES: usize> {
+    first_page: u8,
+    pages: [[u8; MAX_WIDTH as usize]; PAGES],
+    /// Inclusive column range that changed since the last flush, for every page
+    dirty: [Option<(u8, u8)>; PAGES],
+    turn: Turn,
+}
+
+/// Quarter turn a [FrameBuffer] applies to everything drawn into it, for mounting the panel on its side.
+/// The controller can only do upside down by itself (see [Rotation]), this is done in software instead
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Turn {
+    None,
+    /// Top of the picture is on the panel's right edge
+    Clockwise,
+    /// Top of the picture is on the panel's left edge
+    CounterClockwise,
+}
+
+impl<const PAGES: usize> FrameBuffer<PAGES> {
+    /// Blank buffer covering pages `first_page..first_page + PAGES`. Nothing is dirty yet, so
+    /// [SSD1306Display::flush] won't send anything until something is drawn
+    pub const fn new(first_page: u8) -> Self {
+        Self {
+            first_page,
+            pages: [[0x00; MAX_WIDTH as usize]; PAGES],
+            dirty: [None; PAGES],
+            turn: Turn::None,
+        }
+    }
+    /// Turns everything drawn from here on, coordinates then go up to 64 across and 128 down.
+    /// Whatever is already in the buffer stays as it was drawn
+    pub fn set_turn(&mut self, turn: Turn) {
+        self.turn = turn;
+    }
+
+    /// Width and height of the picture, swapped around when it's turned
+    fn picture_size(&self) -> (u8, u8) {
+        match self.turn {
+            Turn::None => (MAX_WIDTH, MAX_HEIGHT),
+            Turn::Clockwise | Turn::CounterClockwise => (MAX_HEIGHT, MAX_WIDTH),
+        }
+    }
+
+    /// Screen coordinates of pixel (`x`, `y`) of the picture, if it's on the screen at all
+    fn to_screen(&self, x: u8, y: u8) -> Option<(u8, u8)> {
+        let (width, height) = self.picture_size();
+        if x >= width || y >= height {
+            return None;
+        }
+        Some(match self.turn {
+            Turn::None => (x, y),
+            Turn::Clockwise => (height - 1 - y, x),
+            Turn::CounterClockwise => (y, width - 1 - x),
+        })
+    }
+
+    /// Turns the pixel at (`x`, `y`) on or off, in screen coordinates (turned ones, see [FrameBuffer::set_turn]).
+    /// Pixels outside of the pages this buffer covers are ignored
+    pub fn set_pixel(&mut self, x: u8, y: u8, on: bool) {
+        let Some((x, y)) = self.to_screen(x, y) else {
+            return;
+        };
+        let Some(page) = self.page_index(x, y) else {
+            return;
+        };
+        let byte = &mut self.pages[page][x as usize];
+        let old = *byte;
+        if on {
+            *byte |= 1 << (y % 8);
+        } else {
+            *byte &= !(1 << (y % 8));
+        }
+        if *byte != old {
+            self.mark_dirty(page, x, x);
+        }
+    }
+    /// Whether the pixel at (`x`, `y`) is on, anything outside of the buffer is off
+    pub fn pixel(&self, x: u8, y: u8) -> bool {
+        self.to_screen(x, y).is_some_and(|(x, y)| {
+            self.page_index(x, y)
+                .is_some_and(|page| self.pages[page][x as usize] & (1 << (y % 8)) != 0)
+        })
+    }
+
+    /// Sets every pixel in the buffer to `on`
+    pub fn fill(&mut self, on: bool) {
+        let byte = if on { 0xFF } else { 0x00 };
+        for page in 0..PAGES {
+            if self.pages[page].iter().any(|&b| b != byte) {
+                self.pages[page] = [byte; MAX_WIDTH as usize];
+                self.mark_dirty(page, 0, MAX_WIDTH - 1);
+            }
+        }
+    }
+    pub fn clear(&mut self) {
+        self.fill(false);
+    }
+    /// Draws `bitmap` with its top left corner at (`x`, `y`), unlike [SSD1306Display::draw_bitmap]
+    /// only the pixels it covers are touched. Anything off of the buffer gets dropped
+    pub fn draw_bitmap(&mut self, x: u8, y: u8, bitmap: &Bitmap) {
+        for dy in 0..bitmap.height {
+            for dx in 0..bitmap.width {
+                let on = bitmap.column(dx, dy / 8) & (1 << (dy % 8)) != 0;
+                self.set_pixel(x.saturating_add(dx), y.saturating_add(dy), on);
+            }
         }
+    }
+
+    fn page_index(&self, x: u8, y: u8) -> Option<usize> {
+        let page = (y / 8).checked_sub(self.first_page)? as usize;
+        (x < MAX_WIDTH && y < MAX_HEIGHT && page < PAGES).then_some(page)
+    }
+
+    fn mark_dirty(&mut self, page: usize, start: u8, end: u8) {
+        self.dirty[page] = Some(match self.dirty[page] {
+            Some((dirty_start, dirty_end)) => (min(dirty_start, start), max(dirty_end, end)),
+            None => (start, end),
+        });
+    }
+}
 
+/// Lets [embedded_graphics] draw into the buffer. The size is always the biggest panel's full screen
+/// (turned, if the buffer is) so everything uses screen coordinates, pixels off of the pages the buffer
+/// covers are dropped
+impl<const PAGES: usize> OriginDimensions for FrameBuffer<PAGES> {
+    fn size(&self) -> Size {
+        let (width, height) = self.picture_size();
+        Size::new(width as u32, height as u32)
+    }
+}
+
+impl<const PAGES: usize> DrawTarget for FrameBuffer<PAGES> {
+    type Color = BinaryColor;
+    type Error = core::convert::Infallible;
+
+    fn draw_iter<P: IntoIterator<Item = Pixel<BinaryColor>>>(
+        &mut self,
+        pixels: P,
+    ) -> Result<(), Self::Error> {
+        for Pixel(point, color) in pixels {
+            if let (Ok(x), Ok(y)) = (u8::try_from(point.x), u8::try_from(point.y)) {
+                self.set_pixel(x, y, color.is_on());
+            }
+        }
         Ok(())
     }
+
+    fn clear(&mut self, color: BinaryColor) -> Result<(), Self::Error> {
+        self.fill(color.is_on());
+        Ok(())
+    }
+}
+
+/// Bitmap font in the display's own layout: every glyph is a run of column bytes (LSB on top) for each
+/// page it covers, one page after the other. All of the glyphs and tables are in flash, see [progmem]
+pub struct Font {
+    /// Height in pixels, anything over 8 spans multiple pages
+    pub height: u8,
+    /// Character of the first glyph in `glyphs`, the rest follow in order
+    pub first: char,
+    pub glyphs: Glyphs,
+    /// Characters outside of the main range that have a glyph anyways, glyph `i` of `extra_glyphs` is for
+    /// `extras[i]`. These get searched one by one
+    pub extras: Slice<char>,
+    pub extra_glyphs: Glyphs,
+    /// Shown for anything the font doesn't have, instead of silently leaving it out
+    pub replacement: Slice<u8>,
+}
+
+/// A run of glyphs, back to back in `data`
+pub struct Glyphs {
+    pub widths: Widths,
+    /// Blank columns added after every glyph. Block and bar glyphs want none, so they tile without gaps
+    pub spacing: u8,
+    pub data: Slice<u8>,
 }
 
-//Assumes 0 = ASCII 0x20 or ASCII 32
-const FONT_DATA: [[u8; 6]; 96] = [
+pub enum Widths {
+    /// Every glyph is this many columns wide
+    Fixed(u8),
+    /// Glyph `i` is `widths[i]` columns wide and starts `offsets[i] * pages` bytes into `data`
+    Proportional {
+        offsets: Slice<u16>,
+        widths: Slice<u8>,
+    },
+}
+
+impl Glyphs {
+    /// Glyph `index` of a font `pages` tall, if there is one
+    fn get(&self, index: u32, pages: u8) -> Option<Glyph> {
+        let pages = pages as usize;
+        //Checked as a u32 before it's narrowed, usize is only 16 bits on AVR
+        let count = match self.widths {
+            Widths::Fixed(width) => self.data.len().checked_div(width as usize * pages)?,
+            Widths::Proportional { offsets, .. } => offsets.len(),
+        } as u32;
+        let index = usize::try_from(index).ok().filter(|_| index < count)?;
+        let (start, width) = match self.widths {
+            Widths::Fixed(width) => (index.checked_mul(width as usize)?, width),
+            Widths::Proportional { offsets, widths } => {
+                (offsets.get(index)? as usize, widths.get(index)?)
+            }
+        };
+        let start = start.checked_mul(pages)?;
+        let end = start.checked_add(width as usize * pages)?;
+        Some(Glyph {
+            data: self.data.range(start..end)?,
+            width,
+            spacing: self.spacing,
+        })
+    }
+}
+
+/// A single character's worth of a [Font]
+#[derive(Clone, Copy)]
+struct Glyph {
+    data: Slice<u8>,
+    width: u8,
+    spacing: u8,
+}
+
+impl Glyph {
+    /// Columns the glyph takes up along with the spacing after it
+    fn advance(&self) -> u8 {
+        self.width + self.spacing
+    }
+
+    /// Column `x` of page `page` after scaling the glyph up `scale` times. Anything past the glyph's
+    /// width is the blank spacing after it
+    fn scaled_column(&self, x: u8, page: u8, scale: u8, font_pages: u8) -> u8 {
+        let column = |page: u8| -> u8 {
+            match self
+                .data
+                .get(page as usize * self.width as usize + x as usize)
+            {
+                Some(byte) if x < self.width => byte,
+                _ => 0x00,
+            }
+        };
+        if scale == 1 {
+            return column(page);
+        }
+
+        //Every pixel turns into `scale` of them, so work out which source pixel every output one is from
+        let mut byte = 0;
+        for bit in 0..8 {
+            let source = (page as u16 * 8 + bit) / scale as u16;
+            if source < font_pages as u16 * 8
+                && column((source / 8) as u8) & (1 << (source % 8)) != 0
+            {
+                byte |= 1 << bit;
+            }
+        }
+        byte
+    }
+}
+
+/// Zeros going out over I2C, this one has to be in RAM
+const BLANK: [u8; 16] = [0x00; 16];
+
+/// Zeros for a thin space, enough for the tallest font
+#[link_section = ".progmem.data"]
+static THIN_SPACE: [u8; 16] = [0x00; 16];
+
+impl Font {
+    /// Pages every line of text takes up
+    pub const fn pages(&self) -> u8 {
+        self.height.div_ceil(8)
+    }
+
+    fn glyph(&self, ch: char) -> Glyph {
+        let pages = self.pages();
+        let sized = |data: Slice<u8>| Glyph {
+            data,
+            width: (data.len() / pages as usize) as u8,
+            spacing: self.glyphs.spacing,
+        };
+        //Thin space for squeezing things in
+        if ch == '\u{2009}' {
+            //SAFETY: Straight out of a progmem static
+            let thin_space = unsafe { Slice::new(&THIN_SPACE) };
+            return sized(
+                thin_space
+                    .range(0..2 * pages as usize)
+                    .unwrap_or(thin_space),
+            );
+        }
+
+        let found = (ch as u32)
+            .checked_sub(self.first as u32)
+            .and_then(|index| self.glyphs.get(index, pages));
+        found
+            .or_else(|| {
+                let index = self.extras.iter().position(|extra| extra == ch)?;
+                self.extra_glyphs.get(index as u32, pages)
+            })
+            .unwrap_or_else(|| sized(self.replacement))
+    }
+
+    /// Pixels `ch` takes up horizontally, including the spacing after it
+    pub fn char_width(&self, ch: char) -> u8 {
+        self.glyph(ch).advance()
+    }
+
+    /// How many pixels wide `str` is when written in this font, ignoring `\r` and `\n`
+    pub fn text_width(&self, str: &str) -> u16 {
+        str.chars()
+            .filter(|&ch| ch != '\r' && ch != '\n')
+            .map(|ch| self.char_width(ch) as u16)
+            .sum()
+    }
+}
+
+/// The original 6x8 font, every character is 5 columns plus a blank one. Also has [EXTRA_GLYPHS]
+pub static DEFAULT_FONT: Font = FONT;
+
+/// [DEFAULT_FONT] with the blank columns around every glyph trimmed off and a single column between
+/// characters, so narrow ones like `i` and `.` take up less room and a lot more fits on a line.
+/// The extras stay as they are, so bars drawn from the block glyphs are still seamless
+pub static PROPORTIONAL_FONT: Font = Font {
+    glyphs: Glyphs {
+        widths: Widths::Proportional {
+            //SAFETY: Both progmem statics
+            offsets: unsafe { Slice::new(&TRIMMED_OFFSETS) },
+            widths: unsafe { Slice::new(&TRIMMED_WIDTHS) },
+        },
+        spacing: 1,
+        ..FONT.glyphs
+    },
+    ..FONT
+};
+
+//SAFETY: Every slice is out of a progmem static
+const FONT: Font = Font {
+    height: 8,
+    first: ' ',
+    glyphs: Glyphs {
+        widths: Widths::Fixed(6),
+        spacing: 0,
+        data: unsafe { Slice::new(FONT_GLYPHS.as_flattened()) },
+    },
+    extras: unsafe { Slice::new(&EXTRA_CHARS) },
+    extra_glyphs: Glyphs {
+        widths: Widths::Fixed(6),
+        spacing: 0,
+        data: unsafe { Slice::new(EXTRA_DATA.as_flattened()) },
+    },
+    replacement: unsafe { Slice::new(&REPLACEMENT) },
+};
+
+//Only the statics below end up on the device, the consts they're made from are just for building them
+#[link_section = ".progmem.data"]
+static FONT_GLYPHS: [[u8; 6]; FONT_DATA.len()] = FONT_DATA;
+#[link_section = ".progmem.data"]
+static TRIMMED_OFFSETS: [u16; FONT_DATA.len()] = TRIMMED.0;
+#[link_section = ".progmem.data"]
+static TRIMMED_WIDTHS: [u8; FONT_DATA.len()] = TRIMMED.1;
+#[link_section = ".progmem.data"]
+static REPLACEMENT: [u8; 6] = REPLACEMENT_GLYPH;
+#[link_section = ".progmem.data"]
+static EXTRA_CHARS: [char; EXTRA_GLYPHS.len()] = extra_chars(&EXTRA_GLYPHS);
+#[link_section = ".progmem.data"]
+static EXTRA_DATA: [[u8; 6]; EXTRA_GLYPHS.len()] = extra_data(&EXTRA_GLYPHS);
+
+/// Where every glyph of [FONT_DATA] starts and how wide it is, without the blank columns around it
+const TRIMMED: ([u16; FONT_DATA.len()], [u8; FONT_DATA.len()]) = trim(&FONT_DATA);
+
+const fn trim<const N: usize>(font: &[[u8; 6]; N]) -> ([u16; N], [u8; N]) {
+    let mut offsets = [0; N];
+    let mut widths = [0; N];
+    let mut i = 0;
+    while i < font.len() {
+        let glyph = font[i];
+        let mut start = 0;
+        while start < glyph.len() && glyph[start] == 0x00 {
+            start += 1;
+        }
+        let mut end = glyph.len();
+        while end > start && glyph[end - 1] == 0x00 {
+            end -= 1;
+        }
+        //Nothing left of a space, give it a bit of room
+        if start == end {
+            (start, end) = (0, 2);
+        }
+        offsets[i] = (i * glyph.len() + start) as u16;
+        widths[i] = (end - start) as u8;
+        i += 1;
+    }
+    (offsets, widths)
+}
+
+//Assumes 0 = ASCII 0x20 or ASCII 32, up to ~. DEL isn't printable, so it gets the replacement glyph
+const FONT_DATA: [[u8; 6]; 95] = [
     [0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // (space)
     [0x00, 0x00, 0x5F, 0x00, 0x00, 0x00], // !
     [0x00, 0x07, 0x00, 0x07, 0x00, 0x00], // "
@@ -284,6 +1877,467 @@ const FONT_DATA: [[u8; 6]; 96] = [
     [0x00, 0x08, 0x36, 0x41, 0x00, 0x00], // {
     [0x00, 0x00, 0x7F, 0x00, 0x00, 0x00], // |
     [0x00, 0x41, 0x36, 0x08, 0x00, 0x00], // }
-    [0x08, 0x08, 0x2A, 0x1C, 0x08, 0x00], // ->
-    [0x08, 0x1C, 0x2A, 0x08, 0x08, 0x00], // <-
+    [0x08, 0x04, 0x08, 0x10, 0x08, 0x00], // ~
 ];
+
+/// Inverted question mark, for anything that isn't in the font (like U+FFFD)
+const REPLACEMENT_GLYPH: [u8; 6] = [0x7D, 0x7E, 0x2E, 0x76, 0x79, 0x00];
+
+/// Symbols past ASCII that come in handy for units and meters. Blocks have no gap after them so a row
+/// of them makes one solid bar
+const EXTRA_GLYPHS: [(char, [u8; 6]); 23] = [
+    ('→', [0x08, 0x08, 0x2A, 0x1C, 0x08, 0x00]),
+    ('←', [0x08, 0x1C, 0x2A, 0x08, 0x08, 0x00]),
+    ('°', [0x00, 0x06, 0x09, 0x09, 0x06, 0x00]),
+    ('±', [0x44, 0x44, 0x5F, 0x44, 0x44, 0x00]),
+    ('²', [0x00, 0x09, 0x0D, 0x0A, 0x00, 0x00]),
+    ('µ', [0xFC, 0x40, 0x40, 0x20, 0x7C, 0x00]), // micro sign
+    ('μ', [0xFC, 0x40, 0x40, 0x20, 0x7C, 0x00]), // greek mu, looks the same
+    ('↑', [0x04, 0x02, 0x7F, 0x02, 0x04, 0x00]),
+    ('↓', [0x10, 0x20, 0x7F, 0x20, 0x10, 0x00]),
+    ('▀', [0x0F, 0x0F, 0x0F, 0x0F, 0x0F, 0x0F]),
+    ('▁', [0x80, 0x80, 0x80, 0x80, 0x80, 0x80]),
+    ('▂', [0xC0, 0xC0, 0xC0, 0xC0, 0xC0, 0xC0]),
+    ('▃', [0xE0, 0xE0, 0xE0, 0xE0, 0xE0, 0xE0]),
+    ('▄', [0xF0, 0xF0, 0xF0, 0xF0, 0xF0, 0xF0]),
+    ('▅', [0xF8, 0xF8, 0xF8, 0xF8, 0xF8, 0xF8]),
+    ('▆', [0xFC, 0xFC, 0xFC, 0xFC, 0xFC, 0xFC]),
+    ('▇', [0xFE, 0xFE, 0xFE, 0xFE, 0xFE, 0xFE]),
+    ('█', [0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF]),
+    ('▌', [0xFF, 0xFF, 0xFF, 0x00, 0x00, 0x00]),
+    ('▐', [0x00, 0x00, 0x00, 0xFF, 0xFF, 0xFF]),
+    ('░', [0x22, 0x88, 0x22, 0x88, 0x22, 0x88]),
+    ('▒', [0x55, 0xAA, 0x55, 0xAA, 0x55, 0xAA]),
+    ('▓', [0xDD, 0x77, 0xDD, 0x77, 0xDD, 0x77]),
+];
+
+const fn extra_chars<const N: usize>(extras: &[(char, [u8; 6]); N]) -> [char; N] {
+    let mut chars = [' '; N];
+    let mut i = 0;
+    while i < N {
+        chars[i] = extras[i].0;
+        i += 1;
+    }
+    chars
+}
+
+const fn extra_data<const N: usize>(extras: &[(char, [u8; 6]); N]) -> [[u8; 6]; N] {
+    let mut data = [[0x00; 6]; N];
+    let mut i = 0;
+    while i < N {
+        data[i] = extras[i].1;
+        i += 1;
+    }
+    data
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_hal_mock::eh1::i2c::{Mock, Transaction};
+    use std::{vec, vec::Vec};
+
+    const A: [u8; 6] = [0x7E, 0x11, 0x11, 0x11, 0x7E, 0x00];
+    const B: [u8; 6] = [0x7F, 0x49, 0x49, 0x49, 0x36, 0x00];
+    const C: [u8; 6] = [0x3E, 0x41, 0x41, 0x41, 0x22, 0x00];
+
+    /// What a single transaction made up of `parts` looks like to the mock, every write gets checked on its own
+    fn transaction(parts: &[&[u8]]) -> Vec<Transaction> {
+        let mut expected = vec![Transaction::transaction_start(DEFAULT_I2C_ADDR)];
+        for part in parts {
+            expected.push(Transaction::write(DEFAULT_I2C_ADDR, part.to_vec()));
+        }
+        expected.push(Transaction::transaction_end(DEFAULT_I2C_ADDR));
+        expected
+    }
+
+    /// Data going to column `col` of RAM page `page` when the controller isn't already there, with the
+    /// address commands in front of it
+    fn ram_at(page: u8, col: u8, parts: &[&[u8]]) -> Vec<Transaction> {
+        let header = [
+            0x80,
+            0xB0 | page,
+            0x80,
+            col & 0xF,
+            0x80,
+            0x10 | (col >> 4),
+            0x40,
+        ];
+        transaction(&[&[&header[..]], parts].concat())
+    }
+
+    /// The parts `count` blank columns get sent as
+    fn blank(count: usize) -> Vec<&'static [u8]> {
+        (0..count)
+            .step_by(BLANK.len())
+            .map(|start| &BLANK[..min(count - start, BLANK.len())])
+            .collect()
+    }
+
+    /// Init sequence of the default config, one entry per write. Indexes are the ones tests patch
+    #[rustfmt::skip]
+    const INIT: [&[u8]; 24] = [
+        &[0x00], &[0xAE], &[0xA8], &[63], &[0xD5], &[0x80], &[0xD3], &[0x00], &[0x40], &[0x8D, 0x14],
+        &[0x20, 0x02], &[0xA1], &[0xC8], &[0xDA], &[0x12], &[0x81], &[0x7F], &[0xD9], &[0xF1], &[0xDB],
+        &[0x40], &[0xA4], &[0xA6], &[0xAF],
+    ];
+    const INIT_MULTIPLEX: usize = 3;
+    const INIT_POWER: usize = 9;
+    const INIT_MEMORY_MODE: usize = 10;
+    const INIT_COM_PINS: usize = 14;
+
+    /// Display set up with `config`, and a bus that expects `init` and then `expected`
+    fn display_with(
+        config: DisplayConfig,
+        init: &[&[u8]],
+        expected: &[Vec<Transaction>],
+    ) -> (SSD1306Display, Mock) {
+        let mut wire = Mock::new(&[&[transaction(init)][..], expected].concat().concat());
+        let display = SSD1306Display::new(&mut wire, config).unwrap();
+        (display, wire)
+    }
+
+    /// Display set up with the default config, and a bus that expects `expected` after the init sequence
+    fn display(expected: &[Vec<Transaction>]) -> (SSD1306Display, Mock) {
+        display_with(DisplayConfig::default(), &INIT, expected)
+    }
+
+    #[test]
+    fn init_sequence() {
+        let (_, mut wire) = display(&[]);
+        wire.done();
+    }
+
+    #[test]
+    fn init_128x32() {
+        let mut init = INIT;
+        init[INIT_MULTIPLEX] = &[31];
+        init[INIT_COM_PINS] = &[0x02];
+        let config = DisplayConfig::new(DisplaySize::Size128x32);
+        let (display, mut wire) = display_with(config, &init, &[]);
+        assert_eq!((display.width(), display.height()), (128, 32));
+        wire.done();
+    }
+
+    /// SH1106 powers up through its DC-DC converter and has no addressing mode to set
+    fn sh1106(expected: &[Vec<Transaction>]) -> (SSD1306Display, Mock) {
+        let mut init = INIT;
+        init[INIT_POWER] = &[0xAD, 0x8B];
+        init[INIT_MEMORY_MODE] = &[0xE3];
+        let config = DisplayConfig {
+            controller: Controller::Sh1106,
+            ..DisplayConfig::default()
+        };
+        display_with(config, &init, expected)
+    }
+
+    #[test]
+    fn sh1106_init_sequence() {
+        let (_, mut wire) = sh1106(&[]);
+        wire.done();
+    }
+
+    #[test]
+    fn sh1106_columns_are_offset() {
+        let (mut display, mut wire) = sh1106(&[ram_at(3, 12, &[&A]), ram_at(0, 2, &[&B])]);
+        display.set_cursor(&mut wire, 10, 3).unwrap();
+        display.write_str(&mut wire, "A").unwrap();
+        display.set_cursor(&mut wire, 0, 0).unwrap();
+        display.write_str(&mut wire, "B").unwrap();
+        wire.done();
+    }
+
+    #[test]
+    fn set_cursor_unbatched() {
+        let (mut display, mut wire) = display(&[
+            transaction(&[&[0x00], &[0xB2]]),
+            transaction(&[&[0x00], &[0x05], &[0x11]]),
+            transaction(&[&[0x40], &A]),
+        ]);
+        display.set_batching(false);
+        display.set_cursor(&mut wire, 21, 2).unwrap();
+        display.write_str(&mut wire, "A").unwrap();
+        assert!(matches!(
+            display.set_cursor(&mut wire, 0, 8),
+            Err(DisplayError::OutOfBounds)
+        ));
+        wire.done();
+    }
+
+    #[test]
+    fn set_cursor_goes_out_with_the_data() {
+        let (mut display, mut wire) = display(&[ram_at(3, 10, &[&A])]);
+        display.set_cursor(&mut wire, 0, 1).unwrap();
+        display.set_cursor(&mut wire, 10, 3).unwrap();
+        display.write_str(&mut wire, "A").unwrap();
+        wire.done();
+    }
+
+    #[test]
+    fn write_str_sends_a_line_at_once() {
+        let (mut display, mut wire) = display(&[
+            ram_at(0, 0, &[&[A, B, C].concat()]),
+            //Carries on right where the controller already is
+            transaction(&[&[0x40], &A]),
+        ]);
+        assert_eq!(display.write_str(&mut wire, "ABC").unwrap(), 3);
+        display.write_str(&mut wire, "A").unwrap();
+        wire.done();
+    }
+
+    #[test]
+    fn write_str_sends_a_full_row_at_once() {
+        let line = [A, B, C].repeat(7);
+        let (mut display, mut wire) = display(&[ram_at(0, 0, &[&line.concat()])]);
+        assert_eq!(display.write_str(&mut wire, &"ABC".repeat(7)).unwrap(), 21);
+        wire.done();
+    }
+
+    #[test]
+    fn write_str_skips_text_already_there() {
+        let (mut display, mut wire) =
+            display(&[ram_at(0, 0, &[&[A, B].concat()]), ram_at(0, 6, &[&C])]);
+        display.write_str(&mut wire, "AB").unwrap();
+        display.set_cursor(&mut wire, 0, 0).unwrap();
+        assert_eq!(display.write_str(&mut wire, "AC").unwrap(), 2);
+        //Nothing left to send at all
+        display.set_cursor(&mut wire, 0, 0).unwrap();
+        display.write_str(&mut wire, "AC").unwrap();
+        wire.done();
+    }
+
+    #[test]
+    fn write_str_redraws_what_was_cleared() {
+        let (mut display, mut wire) = display(&[
+            ram_at(0, 0, &[&A]),
+            ram_at(0, 0, &blank(128)),
+            ram_at(0, 0, &[&A]),
+        ]);
+        display.write_str(&mut wire, "A").unwrap();
+        display.set_cursor(&mut wire, 0, 0).unwrap();
+        display.clear_to_eol(&mut wire).unwrap();
+        display.write_str(&mut wire, "A").unwrap();
+        wire.done();
+    }
+
+    #[test]
+    fn clear_to_eol() {
+        let (mut display, mut wire) = display(&[
+            ram_at(0, 0, &[&[A, B].concat()]),
+            transaction(&[&[&[0x40][..]][..], &blank(116)].concat()),
+            ram_at(0, 12, &[&C]),
+        ]);
+        display.write_str(&mut wire, "AB").unwrap();
+        display.clear_to_eol(&mut wire).unwrap();
+        //Already blank
+        display.clear_to_eol(&mut wire).unwrap();
+        //The cursor stayed put
+        display.write_str(&mut wire, "C").unwrap();
+        wire.done();
+    }
+
+    #[test]
+    fn clear_to_eol_covers_scaled_text() {
+        let (mut display, mut wire) = display(&[
+            ram_at(0, 0, &[&[0x00; 12]]),
+            ram_at(1, 0, &[&[0x00; 12]]),
+            ram_at(0, 12, &blank(116)),
+            ram_at(1, 12, &blank(116)),
+        ]);
+        display.write_str_scaled(&mut wire, " ", 2).unwrap();
+        display.clear_to_eol(&mut wire).unwrap();
+        wire.done();
+    }
+
+    #[test]
+    fn write_field_is_one_transaction() {
+        let (mut display, mut wire) = display(&[
+            ram_at(2, 30, &[&[&[0x00; 12][..], &A, &B].concat()]),
+            //Only the character that changed
+            ram_at(2, 42, &[&C]),
+        ]);
+        display.set_cursor(&mut wire, 30, 2).unwrap();
+        assert_eq!(
+            display
+                .write_field(&mut wire, "AB", 24, Align::Right)
+                .unwrap(),
+            2
+        );
+        display.set_cursor(&mut wire, 30, 2).unwrap();
+        display
+            .write_field(&mut wire, "CB", 24, Align::Right)
+            .unwrap();
+        assert!(matches!(
+            display.write_field(&mut wire, "ABC", 12, Align::Left),
+            Err(PartialWrite {
+                written: 0,
+                error: DisplayError::OutOfBounds
+            })
+        ));
+        wire.done();
+    }
+
+    fn bytes(glyph: Glyph) -> Vec<u8> {
+        glyph.data.iter().collect()
+    }
+
+    #[test]
+    fn ascii_mapping() {
+        assert_eq!(
+            bytes(DEFAULT_FONT.glyph('~')),
+            [0x08, 0x04, 0x08, 0x10, 0x08, 0x00]
+        );
+        assert_eq!(
+            bytes(DEFAULT_FONT.glyph('→')),
+            [0x08, 0x08, 0x2A, 0x1C, 0x08, 0x00]
+        );
+        assert_eq!(bytes(DEFAULT_FONT.glyph('\u{7F}')), REPLACEMENT_GLYPH);
+        assert_eq!(bytes(PROPORTIONAL_FONT.glyph('\u{7F}')), REPLACEMENT_GLYPH);
+    }
+
+    #[test]
+    fn thin_space() {
+        assert_eq!(bytes(DEFAULT_FONT.glyph('\u{2009}')), [0x00; 2]);
+    }
+
+    #[test]
+    fn proportional_spacing_leaves_the_blocks_alone() {
+        assert_eq!(PROPORTIONAL_FONT.text_width("AA"), 12);
+        assert_eq!(PROPORTIONAL_FONT.text_width("i"), 4);
+        //Bars built out of block glyphs don't get gaps between them
+        assert_eq!(PROPORTIONAL_FONT.text_width("███"), 18);
+        assert_eq!(PROPORTIONAL_FONT.char_width('▌'), 6);
+    }
+
+    #[test]
+    fn characters_outside_of_the_font() {
+        //Control characters are below the first glyph, the rest would have aliased onto one with a 16 bit index
+        for ch in ['\t', '\r', '\u{10041}', '\u{FF21}', '\u{FFE9}'] {
+            assert_eq!(bytes(DEFAULT_FONT.glyph(ch)), REPLACEMENT_GLYPH);
+            assert_eq!(bytes(PROPORTIONAL_FONT.glyph(ch)), REPLACEMENT_GLYPH);
+        }
+    }
+
+    #[test]
+    fn unbatched_text_goes_out_per_character() {
+        let (mut display, mut wire) = display(&[
+            transaction(&[&[0x00], &[0xB0]]),
+            transaction(&[&[0x00], &[0x00], &[0x10]]),
+            transaction(&[&[0x40], &A]),
+            transaction(&[&[0x40], &B]),
+        ]);
+        display.set_batching(false);
+        display.set_cursor(&mut wire, 0, 0).unwrap();
+        display.write_str(&mut wire, "AB").unwrap();
+        wire.done();
+    }
+
+    #[test]
+    fn console_new_line_on_the_last_row_scrolls() {
+        //The RAM page coming into view at the bottom is blanked first, then the start line moves down a page
+        let (mut display, mut wire) = display(&[
+            ram_at(7, 0, &[&A]),
+            ram_at(0, 0, &blank(128)),
+            transaction(&[&[0x00], &[0x48]]),
+            ram_at(0, 0, &[&B]),
+        ]);
+        display.set_console(true);
+        display.set_cursor(&mut wire, 0, 7).unwrap();
+        display.write_str(&mut wire, "A\nB").unwrap();
+        wire.done();
+    }
+
+    #[test]
+    fn console_wraps_at_the_right_edge() {
+        let (mut display, mut wire) = display(&[ram_at(0, 120, &[&A]), ram_at(1, 0, &[&B])]);
+        //Outside of console mode running off of the edge is an error, once the A that fit went out
+        display.set_cursor(&mut wire, 120, 0).unwrap();
+        assert!(matches!(
+            display.write_str(&mut wire, "AB"),
+            Err(PartialWrite {
+                written: 1,
+                error: DisplayError::OutOfBounds
+            })
+        ));
+        //In console mode the B goes on the next row instead, the A is already there
+        display.set_console(true);
+        display.set_cursor(&mut wire, 120, 0).unwrap();
+        display.write_str(&mut wire, "AB").unwrap();
+        wire.done();
+    }
+
+    #[test]
+    fn flush_sends_only_the_dirty_columns() {
+        let (mut display, mut wire) = display(&[
+            ram_at(2, 10, &[&[0x01, 0x00, 0x02]]),
+            ram_at(3, 40, &[&[0x08]]),
+        ]);
+        let mut buffer: FrameBuffer<2> = FrameBuffer::new(2);
+        buffer.set_pixel(10, 16, true);
+        buffer.set_pixel(12, 17, true);
+        buffer.set_pixel(40, 27, true);
+        display.flush(&mut wire, &mut buffer).unwrap();
+        //Nothing changed since
+        display.flush(&mut wire, &mut buffer).unwrap();
+        wire.done();
+    }
+
+    #[test]
+    fn pixels_outside_of_the_buffer_are_ignored() {
+        let (mut display, mut wire) = display(&[]);
+        let mut buffer: FrameBuffer<2> = FrameBuffer::new(2);
+        //Pages above and below the buffer, and past the right edge
+        buffer.set_pixel(5, 15, true);
+        buffer.set_pixel(5, 32, true);
+        buffer.set_pixel(MAX_WIDTH, 20, true);
+        buffer
+            .draw_iter([
+                Pixel(Point::new(-1, 20), BinaryColor::On),
+                Pixel(Point::new(5, 300), BinaryColor::On),
+            ])
+            .unwrap();
+        assert!(!buffer.pixel(5, 15) && !buffer.pixel(5, 32));
+        display.flush(&mut wire, &mut buffer).unwrap();
+        wire.done();
+    }
+
+    #[test]
+    fn clockwise_turn() {
+        //The picture is 64 wide and 128 tall, its top left corner is the screen's top right
+        let (mut display, mut wire) =
+            display(&[ram_at(0, 127, &[&[0x01]]), ram_at(1, 124, &[&[0x04]])]);
+        let mut buffer: FrameBuffer<2> = FrameBuffer::new(0);
+        buffer.set_turn(Turn::Clockwise);
+        assert_eq!(buffer.size(), Size::new(64, 128));
+        buffer.set_pixel(0, 0, true);
+        buffer.set_pixel(10, 3, true);
+        //Past the side of the turned picture
+        buffer.set_pixel(64, 0, true);
+        assert!(buffer.pixel(10, 3) && !buffer.pixel(64, 0));
+        display.flush(&mut wire, &mut buffer).unwrap();
+        wire.done();
+    }
+
+    #[test]
+    fn orientation_commands() {
+        let cases = [
+            (Rotation::Normal, Mirror::None, [0xA1, 0xC8]),
+            (Rotation::Normal, Mirror::Horizontal, [0xA0, 0xC8]),
+            (Rotation::Normal, Mirror::Vertical, [0xA1, 0xC0]),
+            (Rotation::UpsideDown, Mirror::None, [0xA0, 0xC0]),
+            (Rotation::UpsideDown, Mirror::Horizontal, [0xA1, 0xC0]),
+            (Rotation::UpsideDown, Mirror::Vertical, [0xA0, 0xC8]),
+        ];
+        let expected: Vec<_> = cases
+            .iter()
+            .map(|(_, _, cmds)| transaction(&[&[0x00], cmds]))
+            .collect();
+        let (mut display, mut wire) = display(&expected);
+        for (rotation, mirror, _) in cases {
+            display
+                .set_orientation(&mut wire, rotation, mirror)
+                .unwrap();
+        }
+        wire.done();
+    }
+}
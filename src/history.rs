@@ -0,0 +1,97 @@
+///Scrolling graph of how loud it's been lately
+///
+/// Every column of the graph is one time bucket holding the loudest reading in it, newest on the right, so the
+/// graph scrolls left as time goes on. The alarm threshold is drawn as a dotted line across it.
+/// Levels are kept at 8 bits in a ring buffer, so the whole history is just 128 bytes, and drawing goes
+/// straight to the display a page at a time through [SSD1306Display::write_columns].
+use crate::display::{DisplayError, SSD1306Display};
+use core::cmp::max;
+use embedded_hal::i2c::I2c as BaseI2c;
+
+/// How many pages tall the graph is
+pub const PAGES: u8 = 2;
+
+const COLUMNS: usize = 128;
+const HEIGHT: u16 = PAGES as u16 * 8;
+
+pub struct History {
+    /// Loudest reading of every bucket, scaled down to 8 bits. Oldest is at `next`
+    levels: [u8; COLUMNS],
+    next: u8,
+    /// Page the graph starts on, it's [PAGES] tall
+    first_page: u8,
+    bucket_ms: u32,
+    bucket_start: u32,
+    bucket_peak: u16,
+}
+
+impl History {
+    /// Empty history where every column covers `bucket_ms` (i.e. 1000 for 1s, 60000 for 1min), drawn on
+    /// pages `first_page..first_page + PAGES`
+    pub const fn new(bucket_ms: u32, first_page: u8) -> Self {
+        Self {
+            levels: [0; COLUMNS],
+            next: 0,
+            first_page,
+            bucket_ms,
+            bucket_start: 0,
+            bucket_peak: 0,
+        }
+    }
+
+    /// Feeds in a reading taken at `now` (ms), returns true when a bucket was finished and the graph changed
+    pub fn record(&mut self, now: u32, level: u16) -> bool {
+        let elapsed = now.wrapping_sub(self.bucket_start);
+        if elapsed < self.bucket_ms {
+            self.bucket_peak = max(self.bucket_peak, level);
+            return false;
+        }
+
+        self.levels[self.next as usize] = (self.bucket_peak >> 2) as u8; //10 bit ADC down to 8
+        self.next = (self.next + 1) % COLUMNS as u8;
+        //Keep buckets on a fixed grid, unless we fell way behind (i.e. a long Morse message)
+        self.bucket_start = if elapsed < self.bucket_ms * 2 {
+            self.bucket_start.wrapping_add(self.bucket_ms)
+        } else {
+            now
+        };
+        self.bucket_peak = level;
+        true
+    }
+
+    /// Draws the whole graph, `threshold` is in the same units as the readings
+    pub fn draw<I: BaseI2c>(
+        &self,
+        display: &mut SSD1306Display,
+        wire: &mut I,
+        threshold: u16,
+    ) -> Result<(), DisplayError<I::Error>> {
+        let threshold_y = HEIGHT - 1 - scale(threshold >> 2);
+        let mut page_buf = [0u8; COLUMNS];
+        for page in 0..PAGES {
+            for (x, byte) in page_buf.iter_mut().enumerate() {
+                let level = self.levels[(self.next as usize + x) % COLUMNS];
+                let bar_top = HEIGHT - scale(level as u16);
+                *byte = 0;
+                for bit in 0..8 {
+                    let y = page as u16 * 8 + bit;
+                    let mut on = y >= bar_top;
+                    //Dotted line, inverted over the bars so it doesn't disappear into them
+                    if y == threshold_y && x % 2 == 0 {
+                        on = !on;
+                    }
+                    if on {
+                        *byte |= 1 << bit;
+                    }
+                }
+            }
+            display.write_columns(wire, 0, self.first_page + page, &page_buf)?;
+        }
+        Ok(())
+    }
+}
+
+/// 8-bit level to bar height in pixels
+fn scale(level: u16) -> u16 {
+    level * HEIGHT / 256
+}
@@ -0,0 +1,11 @@
+//!The SSD1306 display driver, split out of the firmware so it builds on the host too
+//!
+//! Nothing in here touches the AVR directly, the display only needs an [embedded_hal::i2c::I2c] bus and
+//! [progmem] falls back to normal reads off of AVR. That way the driver can be tested against a mock bus
+//! with `cargo test --lib --target x86_64-unknown-linux-gnu` (see the README).
+#![cfg_attr(not(test), no_std)]
+#![cfg_attr(target_arch = "avr", feature(asm_experimental_arch))]
+#![feature(const_slice_flatten)]
+
+pub mod display;
+pub mod progmem;
@@ -2,15 +2,30 @@
 #![no_main]
 #![feature(abi_avr_interrupt)]
 
-mod display;
+mod alarm;
+mod benchmark;
+mod bitmaps;
+mod calibration;
+mod clips;
+mod history;
+mod morse;
+mod panel;
+mod pcm;
+mod screensaver;
+mod serial_rx;
+mod timer;
+mod vu_meter;
 
+use alarm::Effect;
 use arduino_hal::clock::Clock;
+use arduino_hal::prelude::*;
 use core::{
     cmp::{max, min},
     ops::Range,
 };
-use display::SSD1306Display;
+use display::{Bitmap, DisplayConfig, FrameBuffer, SSD1306Display};
 use panic_halt as _;
+use shut_up_device::{display, progmem};
 pub use unwrap_infallible::UnwrapInfallible as _;
 
 //TODO Save this for the blog, panic handler too lorge
@@ -57,47 +72,71 @@ pub use unwrap_infallible::UnwrapInfallible as _;
 /// Reference: https://github.com/arduino/ArduinoCore-avr/blob/master/cores/arduino/Tone.cpp
 /// Some liberties are taken to simplify use
 ///
+/// Unlike the Arduino version, the pin isn't just toggled on every compare match. Timer2 runs in CTC mode
+/// with OCR2A as the top of a full period (pin goes high) and OCR2B partway through it (pin goes low), so the
+/// duty cycle, and with it the volume of the buzzer, can be changed without touching the frequency.
+///
 /// ASSUMPTIONS
-/// - TCCR2A Wave Generation Mode (WGM2) is set to CTC (010) before [tone] calls
 /// - BUZZER_PIN_PORT is initialized in [main]
-/// - TIMSK2_REF is initialized in [main] before [tone] calls
+/// - Interrupts are enabled once in [main], [tone] and [no_tone] only touch the Timer2 interrupt mask
 /// - Buzzer is on Pin 9 (PB1), it is initialized in [main] before [tone] calls to be [avr_hal_generic::port::mode::Output]
 
 //SAFETY: Only used for the buzzer, no multi-buzzer setup expected
 static mut BUZZER_PIN_PORT: *mut u8 = core::ptr::null_mut(); //Set early in main
 const BUZZER_PIN_MASK: u8 = 0b00000010; //Mask for pin 9a
 
-fn tone(tc2: &avr_device::atmega328p::TC2, frequency: u16) {
-    // tc2.tccr2a.write(|w| w.wgm2().ctc());
-    tc2.tccr2a.write(|w| w.wgm2().pwm_phase()); //NOTE talk about tgis in the blog
-
-    let (prescalar_bits, ocr) = {
-        let mut ocr_base = arduino_hal::DefaultClock::FREQ / frequency as u32 / 2 - 1;
-        let mut bits = 0b000;
-        //NOTE this was written out as discrete statements in the original arduino code
-        // partially due to multi-config targeting, but maybe small perf? (i.e. bad loop opts)
-        while ocr_base > 255 && bits < 0b111 {
-            ocr_base = (ocr_base + 1) / 2 - 1; //preserve accuracy
-            bits = bits + 1; //increment bit counter
-        }
-        (bits, ocr_base as u8)
-    };
+/// Loudest volume for [tone_volume], which is a plain 50% duty square wave
+const MAX_VOLUME: u8 = 255;
 
-    tc2.tccr2b.write(|w| w.cs2().bits(prescalar_bits.into()));
+/// Timer2 clock select bits and their prescaler (as a shift, they're all powers of 2). Timer2 has the
+/// extra /32 and /128 steps compared to the other timers, so the bits don't map to a fixed shift.
+/// [crate::alarm] calls [tone_volume] from an ISR, so trying every prescaler is done with shifts. That leaves
+/// two u32 divisions (the period and the duty cycle), which is why the alarm only updates every few ms
+const TIMER2_PRESCALERS: [(u8, u8); 7] = [
+    (0b001, 0),  // /1
+    (0b010, 3),  // /8
+    (0b011, 5),  // /32
+    (0b100, 6),  // /64
+    (0b101, 7),  // /128
+    (0b110, 8),  // /256
+    (0b111, 10), // /1024
+];
 
-    tc2.ocr2a.write(|w| w.bits(ocr));
-    tc2.timsk2.write(|w| w.ocie2a().set_bit());
+fn tone(tc2: &avr_device::atmega328p::TC2, frequency: u16) {
+    tone_volume(tc2, frequency, MAX_VOLUME);
+}
 
-    //SAFETY Interrupts are enabled/disabled through tone/no_tone calls
-    unsafe {
-        avr_device::interrupt::enable();
+/// Same as [tone], but `volume` scales the duty cycle from silent (0) up to 50% ([MAX_VOLUME])
+fn tone_volume(tc2: &avr_device::atmega328p::TC2, frequency: u16, volume: u8) {
+    if frequency == 0 || volume == 0 {
+        no_tone(tc2);
+        return;
     }
+
+    tc2.tccr2a.write(|w| w.wgm2().ctc());
+
+    //Smallest prescaler that fits a full period in the 8-bit counter, which also gives us the
+    // most steps to pick the duty cycle from. Anything below ~61Hz gets clamped to the slowest setting
+    let period = arduino_hal::DefaultClock::FREQ / frequency as u32;
+    let (prescalar_bits, ticks) = TIMER2_PRESCALERS
+        .iter()
+        .map(|&(bits, shift)| (bits, period >> shift))
+        .find(|&(_, ticks)| ticks <= 256)
+        .unwrap_or((0b111, 256));
+    let ticks = max(ticks, 2);
+    let high_ticks = ticks * volume as u32 / (2 * MAX_VOLUME as u32);
+
+    tc2.tccr2b.write(|w| w.cs2().bits(prescalar_bits));
+
+    tc2.ocr2a.write(|w| w.bits((ticks - 1) as u8));
+    tc2.ocr2b.write(|w| w.bits(high_ticks as u8));
+    tc2.timsk2
+        .write(|w| w.ocie2a().set_bit().ocie2b().set_bit());
 }
 
 fn no_tone(tc2: &avr_device::atmega328p::TC2) {
-    tc2.timsk2.write(|w| w.ocie2a().clear_bit());
-    //SAFETY Interrupts are enabled/disabled through tone/no_tone calls
-    avr_device::interrupt::disable();
+    tc2.timsk2
+        .write(|w| w.ocie2a().clear_bit().ocie2b().clear_bit());
 
     //Easier than passing the pin as an arg
     //SAFETY: Only other time it is accessed this way is through ISR, which is now disabled
@@ -112,24 +151,58 @@ fn tone_duration(tc2: &avr_device::atmega328p::TC2, frequency: u16, delay: u16)
     no_tone(tc2);
 }
 
-/// Really basic ISR to toggle the buzzer pin, intentionally lightweight since
+/// Really basic ISRs to drive the buzzer pin, intentionally lightweight since
 /// AVR doesn't have interrupt nesting, so it doesn't take enough timer cycles.
+/// COMPA marks the start of a period (pin high), COMPB the end of the high time (pin low)
 /// Source: https://github.com/Rahix/avr-hal/issues/75#issuecomment-706031854
 /// SAFETY ASSUMPTION - ISR Mask is disabled outside of interrupt space
 #[avr_device::interrupt(atmega328p)]
 fn TIMER2_COMPA() {
     unsafe {
-        *BUZZER_PIN_PORT ^= BUZZER_PIN_MASK; //toggle the port
+        *BUZZER_PIN_PORT |= BUZZER_PIN_MASK; //start of the period
         core::sync::atomic::compiler_fence(core::sync::atomic::Ordering::SeqCst);
     }
 }
 
+#[avr_device::interrupt(atmega328p)]
+fn TIMER2_COMPB() {
+    unsafe {
+        *BUZZER_PIN_PORT &= !BUZZER_PIN_MASK; //end of the high time
+        core::sync::atomic::compiler_fence(core::sync::atomic::Ordering::SeqCst);
+    }
+}
+
+/// Rough loudness in dB from a peak-to-peak mic reading, interpolated between the reference points
+/// in [main]. Past either end, the closest segment just keeps going
+fn approx_db(vpp_raw: u16) -> u16 {
+    //(ADC peak-to-peak, dB)
+    const REFERENCE: [(i32, i32); 3] = [(60, 33), (370, 70), (512, 80)];
+    let raw = vpp_raw as i32;
+    let segment = if raw < REFERENCE[1].0 { 0 } else { 1 };
+    let ((x0, y0), (x1, y1)) = (REFERENCE[segment], REFERENCE[segment + 1]);
+    max(y0 + (raw - x0) * (y1 - y0) / (x1 - x0), 0) as u16
+}
+
+/// A flaky display shouldn't take the whole device down with it, so errors only get logged
+/// and light up the error LED. Drawing just carries on next time around
+fn report_display_error(
+    serial: &mut impl ufmt::uWrite<Error = core::convert::Infallible>,
+    err_led: &mut impl embedded_hal::digital::OutputPin<Error = core::convert::Infallible>,
+    what: &str,
+    err: impl ufmt::uDebug,
+) {
+    ufmt::uwriteln!(serial, "{} error {:?}\r", what, err).unwrap_infallible();
+    err_led.set_high().unwrap_infallible();
+}
+
 /// ---------------------------------------
 /// Firmware Entry
 /// ----------------------------------------
 
 #[arduino_hal::entry]
 fn main() -> ! {
+    const MORSE_WPM: u8 = 15; //Slow enough to follow along without being a Morse nerd
+
     arduino_hal::delay_ms(3000); //Reprogramming Window
 
     let dp = arduino_hal::Peripherals::take().unwrap();
@@ -138,6 +211,7 @@ fn main() -> ! {
         BUZZER_PIN_PORT = dp.PORTB.portb.as_ptr().clone();
     }
     let pins = arduino_hal::pins!(dp);
+    timer::init(dp.TC0);
 
     //Setup MCU Subsystems
     let mut i2c = arduino_hal::I2c::new(
@@ -148,6 +222,7 @@ fn main() -> ! {
     );
     let mut adc = arduino_hal::Adc::new(dp.ADC, Default::default());
     let mut serial = arduino_hal::default_serial!(dp, pins, 57600);
+    let mut eeprom = arduino_hal::Eeprom::new(dp.EEPROM);
 
     //Setup Specific Pins
     let mut led = pins.d13.into_output();
@@ -156,38 +231,199 @@ fn main() -> ! {
     let _buzzer = pins.d9.into_output();
     let mic = pins.a0.into_analog_input(&mut adc);
 
+    //SAFETY - Everything sharing state with an ISR is set up at this point
+    unsafe {
+        avr_device::interrupt::enable();
+    }
+
     tone_duration(&dp.TC2, 2000, 250);
 
-    let mut display = match SSD1306Display::new(&mut i2c) {
+    //Send 'c' over serial while it boots to redo the calibration (i.e. after swapping the buzzer),
+    // 'b' to benchmark the display, or 's' to switch between 128x64 and 128x32 panels
+    let boot_command = serial.read().ok();
+
+    //About half of the units have 128x32 panels, which one is saved in EEPROM
+    let mut panel_size = panel::load(&eeprom);
+    if boot_command == Some(b's') {
+        panel_size = panel::next(panel_size);
+        panel::save(&mut eeprom, panel_size);
+    }
+    ufmt::uwriteln!(
+        &mut serial,
+        "Display: {}x{}\r",
+        panel_size.width(),
+        panel_size.height()
+    )
+    .unwrap_infallible();
+    let mut display = match SSD1306Display::new(&mut i2c, DisplayConfig::new(panel_size)) {
         Ok(disp) => disp,
         Err(_) => {
             ufmt::uwriteln!(&mut serial, "Could not initialize display").unwrap_infallible();
             err_led.set_high();
-            panic!() //No point in continuing without a core piece missing
+            //No point in continuing without a core piece missing, but at least say what's wrong
+            let frequency = calibration::load(&eeprom).unwrap_or(calibration::DEFAULT_FREQUENCY);
+            loop {
+                morse::play(&dp.TC2, &mut led, "OLED", frequency, MORSE_WPM);
+                arduino_hal::delay_ms(2000);
+            }
         }
     };
-    display.clear(&mut i2c).expect("init failed!!");
-    display.write_str(
-        &mut i2c,
-        "SHUT UP DEVICE\nrev 0.1 pre-alpha\nin-engine footage",
-    );
+    if let Err(err) = display.clear(&mut i2c) {
+        report_display_error(&mut serial, &mut err_led, "clear", err);
+    }
+    //The logo fills a 128x32 panel, taller ones also get the version under it
+    if display.width() < bitmaps::LOGO.width() || display.height() < bitmaps::LOGO.height() {
+        if let Err(err) = display.write_str(&mut i2c, "SHUT UP DEVICE") {
+            report_display_error(&mut serial, &mut err_led, "write_str", err);
+        }
+    } else if display.height() < 64 {
+        if let Err(err) = display.draw_bitmap(&mut i2c, 0, 0, &bitmaps::LOGO) {
+            report_display_error(&mut serial, &mut err_led, "draw_bitmap", err);
+        }
+    } else {
+        if let Err(err) = display.draw_bitmap(&mut i2c, 0, 4, &bitmaps::LOGO) {
+            report_display_error(&mut serial, &mut err_led, "draw_bitmap", err);
+        }
+        if let Err(err) = display.set_cursor(&mut i2c, 0, 5) {
+            report_display_error(&mut serial, &mut err_led, "set_cursor", err);
+        } else if let Err(err) = display.write_str(&mut i2c, "rev 0.1 pre-alpha\nin-engine footage")
+        {
+            report_display_error(&mut serial, &mut err_led, "write_str", err);
+        }
+    }
 
     arduino_hal::delay_ms(2000);
-    display.clear(&mut i2c).unwrap();
-
-    //Since we are in no_std land, allocate a buffer for the display strings, then we can use ufmt
-    //heapless crate is based as hell
-    let mut oled_buf1: heapless::String<64> = heapless::String::new();
+    if let Err(err) = display.clear(&mut i2c) {
+        report_display_error(&mut serial, &mut err_led, "clear", err);
+    }
+    //Boot messages scroll by like a terminal until the readout takes over
+    display.set_console(true);
 
+    // sure, we could do async but that's a headache
+    // Timings here are faster than most human reaction speeds, so we should be fine
     const ENUM_RANGE: Range<u16> = 0..100;
-    loop {
-        // sure, we could do async but that's a headache
-        // Timings here are faster than most human reaction speeds, so we should be fine
+    let mut measure_vpp = || {
         let (min_ms, max_ms) = ENUM_RANGE.fold((1024, 0), |(min_val, max_val), _| {
             let measured = arduino_hal::Adc::read_blocking(&mut adc, &mic);
             (min(min_val, measured), max(max_val, measured))
         });
-        let vpp_raw = max_ms - min_ms; //Effectively Vp_p or peak-to-peak voltage in Quantized values
+        max_ms - min_ms //Effectively Vp_p or peak-to-peak voltage in Quantized values
+    };
+
+    let recalibrate = boot_command == Some(b'c');
+    let buzzer_frequency = match calibration::load(&eeprom) {
+        Some(frequency) if !recalibrate => frequency,
+        saved => {
+            if let Err(err) = display.write_str(&mut i2c, "Calibrating buzzer\nkeep it quiet...\n")
+            {
+                report_display_error(&mut serial, &mut err_led, "write_str", err);
+            }
+            match calibration::sweep(&dp.TC2, &mut measure_vpp, &mut serial) {
+                Ok(frequency) => {
+                    calibration::save(&mut eeprom, frequency);
+                    frequency
+                }
+                //Whatever worked before is still better than a made up frequency, and nothing gets saved
+                // so it's tried again next boot
+                Err(err) => {
+                    let (reason, message) = match err {
+                        calibration::SweepError::Saturated => {
+                            ("the mic is saturated", "Mic saturated!\n")
+                        }
+                        calibration::SweepError::NoSignal => {
+                            ("the mic heard nothing", "No mic signal!\n")
+                        }
+                    };
+                    ufmt::uwriteln!(&mut serial, "Calibration failed, {}\r", reason)
+                        .unwrap_infallible();
+                    if let Err(err) = display.write_str(&mut i2c, message) {
+                        report_display_error(&mut serial, &mut err_led, "write_str", err);
+                    }
+                    saved.unwrap_or(calibration::DEFAULT_FREQUENCY)
+                }
+            }
+        }
+    };
+    ufmt::uwriteln!(&mut serial, "Buzzer frequency: {}Hz\r", buzzer_frequency).unwrap_infallible();
+    alarm::set_base_frequency(buzzer_frequency);
+
+    if boot_command == Some(b'b') {
+        display.set_console(false);
+        if let Err(err) = benchmark::run(&mut display, &mut i2c, &mut serial) {
+            report_display_error(&mut serial, &mut err_led, "benchmark", err);
+        }
+        if let Err(err) = display.clear(&mut i2c) {
+            report_display_error(&mut serial, &mut err_led, "clear", err);
+        }
+        display.set_console(true);
+    }
+
+    //Lines sent over serial become the message the alarm beeps out in Morse
+    serial_rx::listen();
+    let mut serial_line: heapless::String<32> = heapless::String::new();
+    let mut morse_message: heapless::String<32> = heapless::String::new();
+
+    //Screen dims after a few quiet minutes and turns off after half an hour, anything louder than
+    // talking wakes it back up
+    const WAKE_THRESHOLD: u16 = 250; //~55dB
+    const SCREEN_DIM_MS: u32 = 5 * 60 * 1000;
+    const SCREEN_OFF_MS: u32 = 30 * 60 * 1000;
+    const PIXEL_SHIFT_MS: u32 = 60 * 1000;
+    let mut screensaver =
+        screensaver::Screensaver::new(SCREEN_DIM_MS, SCREEN_OFF_MS, PIXEL_SHIFT_MS);
+
+    //Alarm starts off quiet and gets louder the longer the noise keeps going
+    const ALARM_THRESHOLD: u16 = 440;
+    const ALARM_START_VOLUME: u8 = 32;
+    const ALARM_VOLUME_STEP: u8 = 16;
+    //Every effect gets this long before the next one takes over. It's a multiple of all of their cycles
+    // (200ms chirps, 2s siren, 1s two-tone, 120ms warble), so none of them get cut off partway
+    const ALARM_EFFECT_MS: u32 = 6000;
+    const ALARM_STEP_MS: u32 = 2000; //Full volume after ~30s of noise
+    let mut alarm_started: Option<u32> = None; //When the noise started, while it keeps going
+    let mut asked_nicely = false;
+
+    if let Err(err) = ufmt::uwrite!(display.writer(&mut i2c), "Buzzer: {}Hz\n", buzzer_frequency) {
+        report_display_error(&mut serial, &mut err_led, "uwrite", err);
+    }
+    arduino_hal::delay_ms(1000);
+    display.set_console(false);
+    if let Err(err) = display.clear(&mut i2c) {
+        report_display_error(&mut serial, &mut err_led, "clear", err);
+    }
+
+    //Big dB readout takes up the top left, small labels and raw values go to the right of it.
+    // Graphs go below that if the panel is tall enough to have a below, the history right under the readout
+    // and the VU meter on the bottom pages
+    const READOUT_SCALE: u8 = 4;
+    let pages = display.height() / 8;
+    let show_graphs = pages >= READOUT_SCALE + history::PAGES + vu_meter::PAGES;
+    let vu_meter_page = pages.saturating_sub(vu_meter::PAGES);
+    let mut vu_meter = vu_meter::VuMeter::new(vu_meter_page);
+    let mut vu_meter_buf: FrameBuffer<{ vu_meter::PAGES as usize }> =
+        FrameBuffer::new(vu_meter_page);
+    const HISTORY_BUCKET_MS: u32 = 1000; //128s of history on screen
+    let mut history = history::History::new(HISTORY_BUCKET_MS, READOUT_SCALE);
+    const LABEL_COL: u8 = 80;
+    //Status icon goes in the top right corner, and only gets redrawn when it changes
+    const STATUS_COL: u8 = 120;
+    let mut status_icon: Option<&Bitmap> = None;
+    for (row, label) in [(0, "dB"), (2, "ADC")] {
+        if let Err(err) = display.set_cursor(&mut i2c, LABEL_COL, row) {
+            report_display_error(&mut serial, &mut err_led, "set_cursor", err);
+        } else if let Err(err) = display.write_str(&mut i2c, label) {
+            report_display_error(&mut serial, &mut err_led, "write_str", err);
+        }
+    }
+
+    loop {
+        if serial_rx::read_line(&mut serial_line) {
+            morse_message = core::mem::take(&mut serial_line);
+            ufmt::uwriteln!(&mut serial, "Morse message: {}\r", morse_message.as_str())
+                .unwrap_infallible();
+        }
+
+        let vpp_raw = measure_vpp();
         let vpp = vpp_raw as f32 / 1024.0 * 5.0;
 
         //After a struggle with trying to understand how the hell you correlate microphone voltage with perceived loudness
@@ -197,39 +433,133 @@ fn main() -> ! {
         // - 1.8V (or ~370 ADC) ~ 70db (talking ~6in away)
         // - 0.3 (or ~60 ADC) ~ 33db (background noise where I did initial testing)
         // Since these measures are _probably_ flawed, erring inbetween 70-80dB ref value
-        if vpp_raw > 440 {
-            tone(&dp.TC2, 2000);
+        if pcm::is_playing() {
+            //Let the clip finish, the mic is just hearing ourselves anyways
+        } else if vpp_raw > ALARM_THRESHOLD {
+            let now = timer::millis();
+            let alarm_ms = now.wrapping_sub(*alarm_started.get_or_insert(now));
+            let steps = min(alarm_ms / ALARM_STEP_MS, MAX_VOLUME as u32) as u8;
+            let alarm_volume =
+                ALARM_START_VOLUME.saturating_add(steps.saturating_mul(ALARM_VOLUME_STEP));
+            if alarm_volume == MAX_VOLUME && !asked_nicely {
+                //Beeping at full blast hasn't worked, try the clip (or the custom message) instead
+                alarm::stop(&dp.TC2);
+                if morse_message.is_empty() {
+                    pcm::play(&dp.TC1, &clips::QUIET_PLEASE);
+                } else {
+                    morse::play(
+                        &dp.TC2,
+                        &mut led,
+                        &morse_message,
+                        buzzer_frequency,
+                        MORSE_WPM,
+                    );
+                }
+                asked_nicely = true;
+            } else {
+                //Switch to something more obnoxious as it keeps going
+                let effect = match alarm_ms / ALARM_EFFECT_MS {
+                    0 => Effect::Chirp,
+                    1 => Effect::Siren,
+                    2 => Effect::TwoTone,
+                    _ => Effect::Warble,
+                };
+                alarm::play(effect, alarm_volume);
+            }
         } else {
-            no_tone(&dp.TC2);
+            alarm::stop(&dp.TC2);
+            alarm_started = None;
+            asked_nicely = false;
         }
 
         ufmt::uwriteln!(
             &mut serial,
-            "{}V,{}\r",
+            "{}V,{},{}dB\r",
             ufmt_float::uFmt_f32::Three(vpp),
-            vpp_raw
+            vpp_raw,
+            approx_db(vpp_raw)
         )
         .unwrap_infallible();
 
-        //Prevents panic from reaching end of buffer
-        //AFAIK uwrite trait can't "seek"
-        oled_buf1.clear();
-        ufmt::uwrite!(
-            &mut oled_buf1,
-            "Vp_p: {}V   \nRaw ADC: {}    ", //Spaces to overwrite, cheaper than clear operation
-            ufmt_float::uFmt_f32::Three(vpp),
-            vpp_raw
-        )
-        .unwrap();
-
-        //Reset Display + Write to it
+        //Values get the rest of their space cleared out, so a shorter one doesn't leave bits of a longer one
         match display.set_cursor(&mut i2c, 0, 0) {
             Ok(_) => (),
-            Err(err) => {
-                ufmt::uwriteln!(&mut serial, "set_cursor error {:?}", err).unwrap_infallible();
-                err_led.set_high();
-            }
+            Err(err) => report_display_error(&mut serial, &mut err_led, "set_cursor", err),
         };
-        display.write_str(&mut i2c, &oled_buf1.as_str());
+        if let Err(err) = ufmt::uwrite!(
+            display.writer(&mut i2c).scaled(READOUT_SCALE),
+            "{}",
+            approx_db(vpp_raw)
+        ) {
+            report_display_error(&mut serial, &mut err_led, "uwrite", err);
+        }
+        if let Err(err) = display.clear_to(&mut i2c, LABEL_COL, READOUT_SCALE) {
+            report_display_error(&mut serial, &mut err_led, "clear_to", err);
+        }
+
+        match display.set_cursor(&mut i2c, LABEL_COL, 1) {
+            Ok(_) => (),
+            Err(err) => report_display_error(&mut serial, &mut err_led, "set_cursor", err),
+        };
+        if let Err(err) = ufmt::uwrite!(
+            display.writer(&mut i2c),
+            "{}V",
+            ufmt_float::uFmt_f32::Three(vpp)
+        ) {
+            report_display_error(&mut serial, &mut err_led, "uwrite", err);
+        }
+        if let Err(err) = display.clear_to_eol(&mut i2c) {
+            report_display_error(&mut serial, &mut err_led, "clear_to_eol", err);
+        }
+
+        match display.set_cursor(&mut i2c, LABEL_COL, 3) {
+            Ok(_) => (),
+            Err(err) => report_display_error(&mut serial, &mut err_led, "set_cursor", err),
+        };
+        if let Err(err) = ufmt::uwrite!(display.writer(&mut i2c), "{}", vpp_raw) {
+            report_display_error(&mut serial, &mut err_led, "uwrite", err);
+        }
+        if let Err(err) = display.clear_to_eol(&mut i2c) {
+            report_display_error(&mut serial, &mut err_led, "clear_to_eol", err);
+        }
+
+        let icon = if err_led.is_set_high() {
+            &bitmaps::ERROR
+        } else if vpp_raw > ALARM_THRESHOLD || pcm::is_playing() {
+            &bitmaps::ALARM
+        } else {
+            &bitmaps::MUTE //Buzzer is quiet
+        };
+        if !status_icon.is_some_and(|shown| core::ptr::eq(shown, icon)) {
+            match display.draw_bitmap(&mut i2c, STATUS_COL, 0, icon) {
+                Ok(_) => status_icon = Some(icon),
+                Err(err) => report_display_error(&mut serial, &mut err_led, "draw_bitmap", err),
+            }
+        }
+        if let Err(err) = screensaver.update(
+            &mut display,
+            &mut i2c,
+            timer::millis(),
+            vpp_raw > WAKE_THRESHOLD,
+        ) {
+            report_display_error(&mut serial, &mut err_led, "screensaver", err);
+        }
+
+        if !show_graphs {
+            continue;
+        }
+        vu_meter.update(vpp_raw);
+        vu_meter
+            .draw(&mut vu_meter_buf, vpp_raw, ALARM_THRESHOLD)
+            .unwrap_infallible();
+        if let Err(err) = display.flush(&mut i2c, &mut vu_meter_buf) {
+            report_display_error(&mut serial, &mut err_led, "flush", err);
+        }
+
+        if history.record(timer::millis(), vpp_raw) {
+            if let Err(err) = history.draw(&mut display, &mut i2c, ALARM_THRESHOLD) {
+                report_display_error(&mut serial, &mut err_led, "history", err);
+            }
+        }
     }
 }
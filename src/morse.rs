@@ -0,0 +1,72 @@
+///Morse code output on the buzzer and an LED
+///
+/// Used for custom messages sent over serial, and for error codes when the display is dead since there's no
+/// other way of telling what went wrong without hooking up a laptop.
+/// Timing follows the PARIS standard: a dot is 1200 / WPM ms, a dash is 3 dots, with 1 dot between
+/// symbols, 3 between letters and 7 between words.
+use crate::{no_tone, tone};
+use embedded_hal::digital::OutputPin;
+use unwrap_infallible::UnwrapInfallible as _;
+
+/// Codes start with a 1 as a marker, after which 0 is a dot and 1 is a dash (i.e. A = .- = 0b101)
+#[rustfmt::skip]
+const LETTERS: [u8; 26] = [
+    0b101,   0b11000, 0b11010, 0b1100,  0b10,    0b10010, 0b1110,  // A-G
+    0b10000, 0b100,   0b10111, 0b1101,  0b10100, 0b111,   0b110,   // H-N
+    0b1111,  0b10110, 0b11101, 0b1010,  0b1000,  0b11,    0b1001,  // O-U
+    0b10001, 0b1011,  0b11001, 0b11011, 0b11100,                   // V-Z
+];
+#[rustfmt::skip]
+const DIGITS: [u8; 10] = [
+    0b111111, 0b101111, 0b100111, 0b100011, 0b100001, // 0-4
+    0b100000, 0b110000, 0b111000, 0b111100, 0b111110, // 5-9
+];
+
+fn encode(ch: char) -> Option<u8> {
+    match ch.to_ascii_uppercase() {
+        ch @ 'A'..='Z' => Some(LETTERS[ch as usize - 'A' as usize]),
+        ch @ '0'..='9' => Some(DIGITS[ch as usize - '0' as usize]),
+        '.' => Some(0b1010101),
+        ',' => Some(0b1110011),
+        '?' => Some(0b1001100),
+        '/' => Some(0b110010),
+        '=' => Some(0b110001),
+        '-' => Some(0b1100001),
+        _ => None,
+    }
+}
+
+/// Plays `message` at `frequency`, blinking `led` along with it. Characters without a Morse code are skipped.
+/// This blocks until the whole message is done
+pub fn play<P: OutputPin<Error = core::convert::Infallible>>(
+    tc2: &avr_device::atmega328p::TC2,
+    led: &mut P,
+    message: &str,
+    frequency: u16,
+    wpm: u8,
+) {
+    let dot = 1200 / core::cmp::max(wpm, 1) as u16;
+
+    for ch in message.chars() {
+        if ch == ' ' {
+            //The last letter already waited 3 dots
+            arduino_hal::delay_ms(dot * 4);
+            continue;
+        }
+        let Some(code) = encode(ch) else {
+            continue;
+        };
+
+        let len = 7 - code.leading_zeros();
+        for bit in (0..len).rev() {
+            let duration = if code & (1 << bit) != 0 { dot * 3 } else { dot };
+            led.set_high().unwrap_infallible();
+            tone(tc2, frequency);
+            arduino_hal::delay_ms(duration);
+            no_tone(tc2);
+            led.set_low().unwrap_infallible();
+            arduino_hal::delay_ms(dot);
+        }
+        arduino_hal::delay_ms(dot * 2);
+    }
+}
@@ -0,0 +1,44 @@
+///Which size of panel is fitted, saved in EEPROM so the same firmware works on every unit
+///
+/// About half of the units have 128x32 panels and the rest 128x64, and there's no asking the controller
+/// which one it's driving. Send 's' over serial while it boots to switch to the other size, it sticks from then on.
+use crate::display::DisplaySize;
+use arduino_hal::Eeprom;
+
+/// What gets used until a size is saved, the panel the firmware was written for
+pub const DEFAULT_SIZE: DisplaySize = DisplaySize::Size128x64;
+
+//Comes after the calibration's bytes, with its own magic byte for the same reason (fresh EEPROM reads 0xFF)
+const EEPROM_MAGIC_OFFSET: u16 = 3;
+const EEPROM_MAGIC: u8 = 0x5A;
+const EEPROM_SIZE_OFFSET: u16 = 4;
+
+/// Size saved by a previous [save], or [DEFAULT_SIZE]
+pub fn load(eeprom: &Eeprom) -> DisplaySize {
+    if eeprom.read_byte(EEPROM_MAGIC_OFFSET) != EEPROM_MAGIC {
+        return DEFAULT_SIZE;
+    }
+    match eeprom.read_byte(EEPROM_SIZE_OFFSET) {
+        1 => DisplaySize::Size128x32,
+        2 => DisplaySize::Size96x16,
+        _ => DisplaySize::Size128x64,
+    }
+}
+
+pub fn save(eeprom: &mut Eeprom, size: DisplaySize) {
+    let byte = match size {
+        DisplaySize::Size128x64 => 0,
+        DisplaySize::Size128x32 => 1,
+        DisplaySize::Size96x16 => 2,
+    };
+    eeprom.write_byte(EEPROM_SIZE_OFFSET, byte);
+    eeprom.write_byte(EEPROM_MAGIC_OFFSET, EEPROM_MAGIC);
+}
+
+/// What 's' switches to from `size`. 96x16 panels are too small for the readout, so they're left out
+pub fn next(size: DisplaySize) -> DisplaySize {
+    match size {
+        DisplaySize::Size128x64 => DisplaySize::Size128x32,
+        DisplaySize::Size128x32 | DisplaySize::Size96x16 => DisplaySize::Size128x64,
+    }
+}
@@ -0,0 +1,89 @@
+///8-bit PCM playback on the buzzer pin
+///
+/// Pin 9 happens to be OC1A, so Timer1 runs in 8-bit fast PWM mode with no prescaler (62.5kHz carrier, way
+/// above what anyone can hear) and OCR1A is set to the current sample. The overflow ISR feeds the next sample
+/// every [OVERFLOWS_PER_SAMPLE] periods, which gives a sample rate of 62.5kHz / 8 = ~7812Hz.
+///
+/// Clips are stored in flash, use `tools/wav2progmem.py` to convert a WAV file into one (see [crate::clips]).
+///
+/// ASSUMPTIONS
+/// - Nothing else is driving the buzzer while a clip plays, stop any [crate::alarm] effect first
+/// - Buzzer is on Pin 9 (PB1), already set as an output in [crate::main]
+use crate::progmem;
+
+const OVERFLOWS_PER_SAMPLE: u8 = 8;
+
+/// Unsigned 8-bit PCM samples stored in flash
+pub struct Clip {
+    samples: *const u8,
+    len: u16,
+}
+
+//SAFETY: Clips only point into flash, which is never written to
+unsafe impl Sync for Clip {}
+
+impl Clip {
+    /// SAFETY: `samples` must point to `len` bytes in a static placed in `.progmem.data`
+    pub const unsafe fn from_progmem(samples: *const u8, len: u16) -> Self {
+        Self { samples, len }
+    }
+}
+
+//SAFETY: Only touched by [play] while the overflow interrupt is off, and by the ISR after
+static mut NEXT_SAMPLE: *const u8 = core::ptr::null();
+static mut END_SAMPLE: *const u8 = core::ptr::null();
+static mut OVERFLOWS: u8 = 0;
+
+/// Starts playing `clip` in the background, replacing whatever clip was already playing
+pub fn play(tc1: &avr_device::atmega328p::TC1, clip: &'static Clip) {
+    tc1.timsk1.write(|w| w.toie1().clear_bit());
+
+    //SAFETY: The overflow interrupt is disabled, so the ISR can't see a half updated clip
+    unsafe {
+        NEXT_SAMPLE = clip.samples;
+        END_SAMPLE = clip.samples.add(clip.len as usize);
+        OVERFLOWS = 0;
+    }
+
+    //Mode 5: Fast PWM, 8-bit, WGM1 is split across both control registers
+    tc1.ocr1a.write(|w| w.bits(0x80)); //Start at the midpoint, silence for unsigned samples
+    tc1.tccr1a
+        .write(|w| w.wgm1().bits(0b01).com1a().match_clear());
+    tc1.tccr1b.write(|w| w.wgm1().bits(0b01).cs1().direct());
+    tc1.timsk1.write(|w| w.toie1().set_bit());
+}
+
+pub fn is_playing() -> bool {
+    //SAFETY: Read only, and the ISR clears the interrupt mask when the clip ends
+    let tc1 = unsafe { avr_device::atmega328p::Peripherals::steal() }.TC1;
+    tc1.timsk1.read().toie1().bit_is_set()
+}
+
+/// Stops playback and hands the pin back to the port, so [crate::tone] works again
+pub fn stop(tc1: &avr_device::atmega328p::TC1) {
+    tc1.timsk1.write(|w| w.toie1().clear_bit());
+    tc1.tccr1a.reset();
+    tc1.tccr1b.reset();
+}
+
+#[avr_device::interrupt(atmega328p)]
+fn TIMER1_OVF() {
+    //SAFETY: See [NEXT_SAMPLE], this is the only place that touches it while the interrupt is on
+    unsafe {
+        OVERFLOWS += 1;
+        if OVERFLOWS < OVERFLOWS_PER_SAMPLE {
+            return;
+        }
+        OVERFLOWS = 0;
+
+        let tc1 = avr_device::atmega328p::Peripherals::steal().TC1;
+        if NEXT_SAMPLE == END_SAMPLE {
+            stop(&tc1);
+            return;
+        }
+
+        tc1.ocr1a
+            .write(|w| w.bits(progmem::read_byte(NEXT_SAMPLE) as u16));
+        NEXT_SAMPLE = NEXT_SAMPLE.add(1);
+    }
+}
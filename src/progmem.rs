@@ -0,0 +1,81 @@
+//!Helpers for data that lives in flash instead of RAM
+//!
+//! AVR is a Harvard architecture, so anything in a plain `static` gets copied into our measly 2KB of SRAM
+//! at startup. Putting it in the `.progmem.data` section keeps it in flash, but then it has to be read back
+//! with the `lpm` instruction instead of a normal load.
+//! Reference: https://github.com/Cryptjar/avr-progmem-rs
+//!
+//! Everywhere else (i.e. running the tests on the host) flash and RAM are the same thing, so it's just a
+//! normal read there.
+
+/// Reads a single byte from flash
+///
+/// # Safety
+/// `p` must point into a static placed in `.progmem.data`
+#[cfg(target_arch = "avr")]
+pub unsafe fn read_byte(p: *const u8) -> u8 {
+    let byte: u8;
+    core::arch::asm!("lpm {}, Z", out(reg) byte, in("Z") p);
+    byte
+}
+
+/// Reads a single byte from flash
+///
+/// # Safety
+/// `p` must point into a static placed in `.progmem.data`
+#[cfg(not(target_arch = "avr"))]
+pub unsafe fn read_byte(p: *const u8) -> u8 {
+    *p
+}
+
+/// Reads a whole `T` from flash, a byte at a time
+///
+/// # Safety
+/// `p` must point into a static placed in `.progmem.data`
+pub unsafe fn read<T: Copy>(p: *const T) -> T {
+    let mut value = core::mem::MaybeUninit::<T>::uninit();
+    let bytes = value.as_mut_ptr() as *mut u8;
+    for i in 0..core::mem::size_of::<T>() {
+        *bytes.add(i) = read_byte((p as *const u8).add(i));
+    }
+    value.assume_init()
+}
+
+/// A slice of a static in `.progmem.data`. It can't be indexed like a normal one (that would read from RAM
+/// at the same address), so elements get copied out of flash with [Slice::get] instead
+#[derive(Clone, Copy)]
+pub struct Slice<T: 'static> {
+    //Only ever used for its address and length, never dereferenced
+    data: &'static [T],
+}
+
+impl<T: Copy> Slice<T> {
+    /// # Safety
+    /// `data` must be (part of) a static placed in `.progmem.data`
+    pub const unsafe fn new(data: &'static [T]) -> Self {
+        Self { data }
+    }
+
+    pub const fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Element `index`, or `None` if it's out of bounds
+    pub fn get(&self, index: usize) -> Option<T> {
+        //SAFETY: In bounds, and in flash as promised by [Slice::new]
+        (index < self.len()).then(|| unsafe { read(self.data.as_ptr().add(index)) })
+    }
+
+    /// The elements in `range`, or `None` if it's out of bounds. Nothing gets read
+    pub fn range(&self, range: core::ops::Range<usize>) -> Option<Self> {
+        self.data.get(range).map(|data| Self { data })
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = T> + '_ {
+        (0..self.len()).filter_map(|index| self.get(index))
+    }
+}
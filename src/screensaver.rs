@@ -0,0 +1,95 @@
+///Keeps the OLED from burning in while nobody is around to look at it
+///
+/// After a while without anything going on the screen gets dimmed, and a while after that it's turned
+/// off completely, until there's something to show again. While it's on, the whole picture moves up a
+/// pixel and back every so often, so the parts that never change (labels, icons) don't keep wearing out
+/// the same pixels. Everything is done with display commands, nothing has to be redrawn.
+use crate::display::{DisplayError, SSD1306Display};
+use embedded_hal::i2c::I2c as BaseI2c;
+
+/// Contrast while dimmed, still readable up close
+const DIM_CONTRAST: u8 = 0x01;
+/// How far the picture moves, see [SSD1306Display::set_pixel_shift]
+const SHIFT_ROWS: u8 = 1;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum State {
+    Awake,
+    Dimmed,
+    Off,
+}
+
+pub struct Screensaver {
+    dim_after_ms: u32,
+    off_after_ms: u32,
+    shift_every_ms: u32,
+    state: State,
+    last_active: u32,
+    last_shift: u32,
+    /// Contrast to go back to when waking up, whatever it was set to before dimming
+    awake_contrast: u8,
+}
+
+impl Screensaver {
+    /// Dims the screen `dim_after_ms` after the last activity and turns it off `off_after_ms` after it,
+    /// and moves the picture every `shift_every_ms` while it's on
+    pub const fn new(dim_after_ms: u32, off_after_ms: u32, shift_every_ms: u32) -> Self {
+        Self {
+            dim_after_ms,
+            off_after_ms,
+            shift_every_ms,
+            state: State::Awake,
+            last_active: 0,
+            last_shift: 0,
+            awake_contrast: crate::display::DEFAULT_CONTRAST,
+        }
+    }
+
+    /// Call every loop with the time (ms) and whether there's anything going on worth looking at.
+    /// Only sends anything to the display when the state changes or it's time to shift
+    pub fn update<I: BaseI2c>(
+        &mut self,
+        display: &mut SSD1306Display,
+        wire: &mut I,
+        now: u32,
+        active: bool,
+    ) -> Result<(), DisplayError<I::Error>> {
+        if active {
+            self.last_active = now;
+        }
+        let quiet_ms = now.wrapping_sub(self.last_active);
+        let state = if quiet_ms >= self.off_after_ms {
+            State::Off
+        } else if quiet_ms >= self.dim_after_ms {
+            State::Dimmed
+        } else {
+            State::Awake
+        };
+
+        if state != self.state {
+            //Already dimmed means a previous try failed partway, that's not the contrast to go back to
+            if self.state == State::Awake && display.contrast() != DIM_CONTRAST {
+                self.awake_contrast = display.contrast();
+            }
+            let contrast = match state {
+                State::Awake => self.awake_contrast,
+                State::Dimmed | State::Off => DIM_CONTRAST,
+            };
+            display.set_contrast(wire, contrast)?;
+            display.set_display_on(wire, state != State::Off)?;
+            self.state = state;
+        }
+
+        //No point in moving a picture nobody can see
+        if state != State::Off && now.wrapping_sub(self.last_shift) >= self.shift_every_ms {
+            let rows = if display.pixel_shift() == 0 {
+                SHIFT_ROWS
+            } else {
+                0
+            };
+            display.set_pixel_shift(wire, rows)?;
+            self.last_shift = now;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,46 @@
+///Interrupt driven receive buffer for the serial port
+///
+/// The main loop spends most of its time sampling the mic and pushing bytes to the display, and the USART
+/// only holds on to a couple of bytes, so anything longer than that would get dropped if it was just polled.
+use avr_device::interrupt::Mutex;
+use core::cell::RefCell;
+use heapless::Deque;
+
+static RX_BUFFER: Mutex<RefCell<Deque<u8, 32>>> = Mutex::new(RefCell::new(Deque::new()));
+
+/// Starts buffering received bytes. After this, reading from the serial port directly won't see anything
+pub fn listen() {
+    //SAFETY: Only the interrupt enable bit is touched, the rest of the USART stays with arduino_hal
+    let dp = unsafe { avr_device::atmega328p::Peripherals::steal() };
+    dp.USART0.ucsr0b.modify(|_, w| w.rxcie0().set_bit());
+}
+
+/// Moves received bytes into `line` until a line ending comes in, returns true when `line` holds a full line.
+/// Anything past the capacity of `line` gets dropped
+pub fn read_line<const N: usize>(line: &mut heapless::String<N>) -> bool {
+    avr_device::interrupt::free(|cs| {
+        let mut buffer = RX_BUFFER.borrow(cs).borrow_mut();
+        while let Some(byte) = buffer.pop_front() {
+            match byte {
+                b'\r' | b'\n' if !line.is_empty() => return true,
+                b'\r' | b'\n' => (),
+                byte if byte.is_ascii() => {
+                    let _ = line.push(byte as char);
+                }
+                _ => (),
+            }
+        }
+        false
+    })
+}
+
+#[avr_device::interrupt(atmega328p)]
+fn USART_RX() {
+    //SAFETY: Reading UDR0 is what clears the interrupt, nothing else reads it while listening
+    let dp = unsafe { avr_device::atmega328p::Peripherals::steal() };
+    let byte = dp.USART0.udr0.read().bits();
+    avr_device::interrupt::free(|cs| {
+        //Full buffer means main isn't keeping up, just drop it
+        let _ = RX_BUFFER.borrow(cs).borrow_mut().push_back(byte);
+    });
+}
@@ -0,0 +1,41 @@
+///Millisecond clock running off of Timer0, similar to Arduino's `millis()`
+///
+/// Every tick also drives whatever [crate::alarm] effect is playing, so those can change over time
+/// without blocking the main loop.
+/// Reference: https://github.com/Rahix/avr-hal/blob/main/examples/arduino-uno/src/bin/uno-millis.rs
+use avr_device::interrupt::Mutex;
+use core::cell::Cell;
+
+//16MHz / 64 / 250 = 1kHz
+const PRESCALER: u32 = 64;
+const TIMER_COUNTS: u32 = 250;
+const MILLIS_INCREMENT: u32 = PRESCALER * TIMER_COUNTS / 16000;
+
+static MILLIS_COUNTER: Mutex<Cell<u32>> = Mutex::new(Cell::new(0));
+
+/// Starts the clock. Interrupts still have to be enabled afterwards for it to actually tick
+pub fn init(tc0: avr_device::atmega328p::TC0) {
+    tc0.tccr0a.write(|w| w.wgm0().ctc());
+    tc0.ocr0a.write(|w| w.bits(TIMER_COUNTS as u8 - 1));
+    tc0.tccr0b.write(|w| w.cs0().prescale_64());
+    tc0.timsk0.write(|w| w.ocie0a().set_bit());
+
+    avr_device::interrupt::free(|cs| MILLIS_COUNTER.borrow(cs).set(0));
+}
+
+/// Milliseconds since [init], wraps after ~49 days
+pub fn millis() -> u32 {
+    avr_device::interrupt::free(|cs| MILLIS_COUNTER.borrow(cs).get())
+}
+
+#[avr_device::interrupt(atmega328p)]
+fn TIMER0_COMPA() {
+    let now = avr_device::interrupt::free(|cs| {
+        let counter = MILLIS_COUNTER.borrow(cs);
+        let now = counter.get().wrapping_add(MILLIS_INCREMENT);
+        counter.set(now);
+        now
+    });
+
+    crate::alarm::tick(now);
+}
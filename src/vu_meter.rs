@@ -0,0 +1,127 @@
+///Bar graph of the current loudness, readable from across the room unlike `Vp_p: 1.234V`
+///
+/// The bar spans the full ADC range, with the alarm threshold as a marker (notch above the bar, inverted inside it)
+/// and a peak-hold tick that sticks around for a bit before falling back down.
+/// Meant to be drawn into a [crate::display::FrameBuffer] covering the [PAGES] it's on. Every pixel of
+/// the meter gets drawn every time instead of clearing first, so only the parts that changed need flushing.
+use core::cmp::{max, min};
+use embedded_graphics::{
+    pixelcolor::BinaryColor,
+    prelude::*,
+    primitives::{Line, PrimitiveStyle, Rectangle},
+};
+
+/// How many pages tall the meter is
+pub const PAGES: u8 = 2;
+
+/// Readings are peak-to-peak ADC values, so this is the loudest it gets
+const FULL_SCALE: u32 = 1023;
+const BAR_LEFT: i32 = 2;
+const BAR_WIDTH: u32 = 124;
+/// Rows from the top of the meter down to the bar
+const BAR_OFFSET: i32 = 5;
+const BAR_HEIGHT: u32 = 9;
+
+/// Updates the peak tick stays put for before it starts falling
+const PEAK_HOLD: u8 = 20;
+/// How much the peak tick falls by every update once the hold is over
+const PEAK_DECAY: u16 = 8;
+
+pub struct VuMeter {
+    peak: u16,
+    hold: u8,
+    /// Row the meter starts on
+    top: i32,
+}
+
+impl VuMeter {
+    /// Meter drawn on pages `first_page..first_page + PAGES`, the buffer for it should start there
+    pub const fn new(first_page: u8) -> Self {
+        Self {
+            peak: 0,
+            hold: 0,
+            top: first_page as i32 * 8,
+        }
+    }
+
+    /// Feeds in a new reading, should be called once per measurement
+    pub fn update(&mut self, level: u16) {
+        if level >= self.peak {
+            self.peak = level;
+            self.hold = PEAK_HOLD;
+        } else if self.hold > 0 {
+            self.hold -= 1;
+        } else {
+            self.peak = max(self.peak.saturating_sub(PEAK_DECAY), level);
+        }
+    }
+
+    pub fn draw<D: DrawTarget<Color = BinaryColor>>(
+        &self,
+        target: &mut D,
+        level: u16,
+        threshold: u16,
+    ) -> Result<(), D::Error> {
+        let on = PrimitiveStyle::with_fill(BinaryColor::On);
+        let off = PrimitiveStyle::with_fill(BinaryColor::Off);
+        let level_width = scale(level);
+        let threshold_x = BAR_LEFT + scale(threshold) as i32;
+        let bar_top = self.top + BAR_OFFSET;
+
+        Rectangle::new(Point::new(0, bar_top - 2), Size::new(128, BAR_HEIGHT + 4))
+            .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1))
+            .draw(target)?;
+        //Gap between the outline and the bar
+        Rectangle::new(Point::new(1, bar_top - 1), Size::new(126, BAR_HEIGHT + 2))
+            .into_styled(PrimitiveStyle::with_stroke(BinaryColor::Off, 1))
+            .draw(target)?;
+
+        Rectangle::new(
+            Point::new(BAR_LEFT, bar_top),
+            Size::new(level_width, BAR_HEIGHT),
+        )
+        .into_styled(on)
+        .draw(target)?;
+        Rectangle::new(
+            Point::new(BAR_LEFT + level_width as i32, bar_top),
+            Size::new(BAR_WIDTH - level_width, BAR_HEIGHT),
+        )
+        .into_styled(off)
+        .draw(target)?;
+
+        let peak_x = BAR_LEFT + scale(self.peak) as i32;
+        Line::new(
+            Point::new(peak_x, bar_top),
+            Point::new(peak_x, bar_top + BAR_HEIGHT as i32 - 1),
+        )
+        .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1))
+        .draw(target)?;
+
+        //Threshold notch above the bar, and inverted inside of it so it shows up over the filled part too
+        Rectangle::new(Point::new(0, self.top), Size::new(128, 3))
+            .into_styled(off)
+            .draw(target)?;
+        Line::new(
+            Point::new(threshold_x, self.top),
+            Point::new(threshold_x, bar_top - 2),
+        )
+        .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1))
+        .draw(target)?;
+        let inside = if threshold_x < BAR_LEFT + level_width as i32 {
+            BinaryColor::Off
+        } else {
+            BinaryColor::On
+        };
+        Line::new(
+            Point::new(threshold_x, bar_top),
+            Point::new(threshold_x, bar_top + BAR_HEIGHT as i32 - 1),
+        )
+        .into_styled(PrimitiveStyle::with_stroke(inside, 1))
+        .draw(target)
+    }
+}
+
+/// Reading to bar width in pixels
+fn scale(level: u16) -> u32 {
+    min(level as u32, FULL_SCALE) * BAR_WIDTH / (FULL_SCALE + 1)
+}
@@ -0,0 +1,286 @@
+#!/usr/bin/env python3
+"""Converts BDF bitmap fonts into `Font`s for `src/display.rs`.
+
+Glyphs come out in the display's own layout: column bytes (LSB on top) for every page the font
+covers, one page after the other. Only the characters asked for get converted, the longest run of
+consecutive ones becomes the main table and the rest go into the font's extras.
+
+Usage: python3 tools/bdf2font.py 7x14.bdf --chars "0-9.:-" --name BIG_DIGITS > src/fonts.rs
+"""
+
+import argparse
+import os
+import sys
+
+
+def parse_bdf(path):
+    """Returns the font's height and ascent, along with every glyph by character as
+    (advance, bbx width, bbx height, x offset, y offset, rows of bits)"""
+    glyphs = {}
+    ascent = descent = None
+    bbox_height = bbox_yoff = 0
+    with open(path, encoding="latin-1") as bdf:
+        lines = iter(bdf.read().splitlines())
+
+    for line in lines:
+        key, _, value = line.partition(" ")
+        if key == "FONTBOUNDINGBOX":
+            _, bbox_height, _, bbox_yoff = map(int, value.split())
+        elif key == "FONT_ASCENT":
+            ascent = int(value)
+        elif key == "FONT_DESCENT":
+            descent = int(value)
+        elif key == "STARTCHAR":
+            code = advance = bbx = None
+            for line in lines:
+                key, _, value = line.partition(" ")
+                if key == "ENCODING":
+                    code = int(value.split()[0])
+                elif key == "DWIDTH":
+                    advance = int(value.split()[0])
+                elif key == "BBX":
+                    bbx = tuple(map(int, value.split()))
+                elif key == "BITMAP":
+                    rows = []
+                    for line in lines:
+                        if line.startswith("ENDCHAR"):
+                            break
+                        bits = bin(int(line, 16))[2:].zfill(len(line) * 4)
+                        rows.append([bit == "1" for bit in bits[: bbx[0]]])
+                    if code is not None and code >= 0 and bbx is not None:
+                        glyphs[chr(code)] = (advance, *bbx, rows)
+                    break
+
+    # Fall back on the bounding box for fonts without the properties
+    if ascent is None or descent is None:
+        descent = -bbox_yoff
+        ascent = bbox_height - descent
+    return ascent + descent, ascent, glyphs
+
+
+def parse_chars(spec):
+    """`0-9.:-` style list, a `-` between two characters is a range (use `\\-` for a literal dash
+    in the middle). Ranges can also be hex codepoints like `0x20-0x7E`"""
+    chars = set()
+    for part in spec.split(","):
+        if part.startswith("0x") and "-0x" in part:
+            start, end = part.split("-")
+            chars.update(map(chr, range(int(start, 16), int(end, 16) + 1)))
+            continue
+        i = 0
+        while i < len(part):
+            if part[i] == "\\" and i + 1 < len(part):
+                chars.add(part[i + 1])
+                i += 2
+            elif i + 2 < len(part) and part[i + 1] == "-":
+                chars.update(map(chr, range(ord(part[i]), ord(part[i + 2]) + 1)))
+                i += 3
+            else:
+                chars.add(part[i])
+                i += 1
+    return chars
+
+
+def render_glyph(glyph, height, ascent, proportional):
+    """Returns the glyph's width and bytes, page after page"""
+    advance, width, rows_count, xoff, yoff, rows = glyph
+    # Pixel (x, y) of the cell, y counted down from the top
+    pixels = set()
+    for j, row in enumerate(rows):
+        y = ascent - (yoff + rows_count) + j
+        for i, on in enumerate(row):
+            if on and 0 <= y < height:
+                pixels.add((xoff + i, y))
+
+    if proportional:
+        # Trim down to the ink, the font's spacing goes between glyphs instead
+        if pixels:
+            left = min(x for x, _ in pixels)
+            cols = max(x for x, _ in pixels) - left + 1
+        else:
+            left, cols = 0, max(1, advance // 2)
+    else:
+        left, cols = 0, advance
+
+    data = []
+    for page in range((height + 7) // 8):
+        for x in range(left, left + cols):
+            byte = 0
+            for bit in range(8):
+                if (x, page * 8 + bit) in pixels:
+                    byte |= 1 << bit
+            data.append(byte)
+    return cols, data
+
+
+def longest_run(chars):
+    """Longest stretch of consecutive characters, ties go to the lowest one"""
+    best = []
+    run = []
+    for ch in sorted(chars):
+        run = run + [ch] if run and ord(ch) == ord(run[-1]) + 1 else [ch]
+        if len(run) > len(best):
+            best = run
+    return best
+
+
+def rust_char(ch):
+    if ch == "'" or ch == "\\":
+        return "'\\" + ch + "'"
+    if " " <= ch <= "~":
+        return f"'{ch}'"
+    return f"'\\u{{{ord(ch):04X}}}'"
+
+
+def hex_bytes(data):
+    return ", ".join(f"0x{b:02X}" for b in data)
+
+
+def progmem_static(name, kind, values, comments=None):
+    """A static in flash, one line per glyph if there are `comments` for them"""
+    out = ["#[rustfmt::skip]", '#[link_section = ".progmem.data"]']
+    out.append(f"static {name}: [{kind}; {len(values) if comments is None else sum(map(len, values))}] = [")
+    if comments is None:
+        for i in range(0, len(values), 16):
+            out.append("    " + " ".join(f"{value}," for value in values[i : i + 16]))
+    else:
+        for data, comment in zip(values, comments):
+            out.append(f"    {hex_bytes(data)}, // {comment}" if data else f"    // {comment}")
+    out.append("];")
+    return out
+
+
+def glyphs(name, glyphs, proportional, fixed_width, spacing):
+    """Statics for a run of glyphs, and the `Glyphs` that goes in the font for them"""
+    out = progmem_static(f"{name}_DATA", "u8", [data for _, _, data in glyphs], [ch for ch, _, _ in glyphs])
+    if proportional:
+        offsets = []
+        column = 0
+        for _, width, _ in glyphs:
+            offsets.append(column)
+            column += width
+        out += progmem_static(f"{name}_OFFSETS", "u16", offsets)
+        out += progmem_static(f"{name}_WIDTHS", "u8", [width for _, width, _ in glyphs])
+        widths = (
+            f"Widths::Proportional {{ offsets: unsafe {{ Slice::new(&{name}_OFFSETS) }}, "
+            f"widths: unsafe {{ Slice::new(&{name}_WIDTHS) }} }}"
+        )
+    else:
+        widths = f"Widths::Fixed({fixed_width})"
+    return out, f"Glyphs {{ widths: {widths}, spacing: {spacing}, data: unsafe {{ Slice::new(&{name}_DATA) }} }}"
+
+
+def render(path, name, height, run, extras, replacement, proportional, spacing):
+    out = [
+        "//Generated by tools/bdf2font.py, do not edit by hand",
+        f"//From {os.path.basename(path)}: "
+        + "".join(ch for ch, _, _ in run)
+        + (" + " + "".join(ch for ch, _, _ in extras) if extras else ""),
+        "use crate::display::{Font, Glyphs, Widths};",
+        "use crate::progmem::Slice;",
+        "",
+    ]
+    run_statics, run_glyphs = glyphs(name, run, proportional, run[0][1], spacing)
+    extra_statics, extra_glyphs = glyphs(f"{name}_EXTRA", extras, proportional, run[0][1], spacing)
+    out += run_statics + extra_statics
+    out += progmem_static(f"{name}_EXTRAS", "char", [rust_char(ch) for ch, _, _ in extras])
+    out += progmem_static(f"{name}_REPLACEMENT", "u8", [replacement], ["replacement"])
+
+    out.append("")
+    out.append("//SAFETY: Every slice is out of one of the progmem statics above")
+    out.append("#[rustfmt::skip]")
+    out.append(f"pub static {name}: Font = Font {{")
+    out.append(f"    height: {height},")
+    out.append(f"    first: {rust_char(run[0][0])},")
+    out.append(f"    glyphs: {run_glyphs},")
+    out.append(f"    extras: unsafe {{ Slice::new(&{name}_EXTRAS) }},")
+    out.append(f"    extra_glyphs: {extra_glyphs},")
+    out.append(f"    replacement: unsafe {{ Slice::new(&{name}_REPLACEMENT) }},")
+    out.append("};")
+    return "\n".join(out) + "\n"
+
+
+def main():
+    parser = argparse.ArgumentParser(description=__doc__.splitlines()[0])
+    parser.add_argument("bdf", help="BDF font to convert")
+    parser.add_argument(
+        "--chars",
+        default="0x20-0x7E",
+        help="characters to convert, i.e. '0-9.:-' (default: printable ASCII)",
+    )
+    parser.add_argument("--name", help="name of the generated static (default: from the file name)")
+    parser.add_argument(
+        "--proportional",
+        action="store_true",
+        help="trim every glyph down to its ink instead of keeping the font's advance",
+    )
+    parser.add_argument(
+        "--spacing",
+        type=int,
+        help="blank columns between glyphs (default: 1 when proportional, 0 otherwise)",
+    )
+    parser.add_argument(
+        "--replacement",
+        default="?",
+        help="character shown for anything not in the font (default: '?', a box if the font lacks it)",
+    )
+    args = parser.parse_args()
+
+    height, ascent, glyphs = parse_bdf(args.bdf)
+    if height > 64:
+        sys.exit(f"{args.bdf}: {height}px is taller than the display")
+    name = args.name or "FONT_" + "".join(
+        c if c.isalnum() else "_" for c in os.path.splitext(os.path.basename(args.bdf))[0]
+    ).upper()
+    spacing = args.spacing if args.spacing is not None else int(args.proportional)
+
+    wanted = parse_chars(args.chars)
+    missing = sorted(wanted - glyphs.keys())
+    if missing:
+        print(f"warning: not in the font: {''.join(missing)!r}", file=sys.stderr)
+    chars = wanted & glyphs.keys()
+    if not chars:
+        sys.exit(f"{args.bdf}: none of the characters are in the font")
+
+    if not args.proportional:
+        advances = {glyphs[ch][0] for ch in chars}
+        if len(advances) > 1:
+            sys.exit(f"{args.bdf}: glyphs aren't all the same width, use --proportional")
+
+    def convert(ch):
+        width, data = render_glyph(glyphs[ch], height, ascent, args.proportional)
+        return ch, width, data
+
+    run = [convert(ch) for ch in longest_run(chars)]
+    extras = [convert(ch) for ch in sorted(chars - {ch for ch, _, _ in run})]
+    if args.replacement in glyphs:
+        _, _, replacement = convert(args.replacement)
+    else:
+        # Outline of a box as wide as the widest glyph
+        width = max(width for _, width, _ in run + extras)
+        box = {(x, y) for x in range(width) for y in range(height)}
+        inside = {(x, y) for x in range(1, width - 1) for y in range(1, height - 1)}
+        pages = (height + 7) // 8
+        replacement = [
+            sum(1 << bit for bit in range(8) if (x, page * 8 + bit) in box - inside)
+            for page in range(pages)
+            for x in range(width)
+        ]
+
+    # Everything but the Font itself stays in flash, which is shared with the firmware
+    size = sum(len(data) for _, _, data in run + extras) + len(replacement) + len(extras) * 4
+    if args.proportional:
+        size += (len(run) + len(extras)) * 3
+    # Proportional offsets are u16 columns
+    columns = max(sum(width for _, width, _ in glyphs) for glyphs in (run, extras))
+    if args.proportional and columns > 0xFFFF:
+        sys.exit(f"{name}: {columns} columns of glyphs is too many for the offset table, convert fewer characters")
+    print(f"{name}: {len(run) + len(extras)} glyphs, {size} bytes of flash", file=sys.stderr)
+
+    sys.stdout.write(
+        render(args.bdf, name, height, run, extras, replacement, args.proportional, spacing)
+    )
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,12 @@
+#!/bin/sh
+#Runs the library's tests (the display driver against a mock I2C bus) on the host.
+#
+# .cargo/config.toml builds everything for the AVR with only `core`, which the test harness can't work with.
+# Cargo only picks that config up from the directory it's run in, so this runs it from outside of the repo
+# instead, with the same toolchain. Extra arguments go to `cargo test`, i.e. a test name to filter on.
+set -e
+root=$(cd "$(dirname "$0")/.." && pwd)
+toolchain=$(sed -n 's/^channel = "\(.*\)"/\1/p' "$root/rust-toolchain.toml")
+host=$(rustc +"$toolchain" -vV | sed -n 's/^host: //p')
+cd /
+exec cargo +"$toolchain" test --manifest-path "$root/Cargo.toml" --lib --target "$host" "$@"
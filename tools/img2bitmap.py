@@ -0,0 +1,221 @@
+#!/usr/bin/env python3
+"""Converts PNG and PBM images into monochrome bitmaps stored in flash, for `src/display.rs`.
+
+Each input file becomes a `pub static <STEM>: Bitmap` in the generated module, so
+`assets/icons/mute.pbm` turns into `bitmaps::MUTE`. Dark pixels are the lit ones (like in PBM,
+use --invert for the opposite), and transparent pixels in a PNG are always off.
+PNGs can be any bit depth and color type, as long as they aren't interlaced.
+
+Usage: python3 tools/img2bitmap.py assets/logo.pbm assets/icons/*.pbm > src/bitmaps.rs
+"""
+
+import argparse
+import os
+import struct
+import sys
+import zlib
+
+# Width and height are a u8 on the firmware side, the display is only 128x64 anyways
+MAX_SIZE = 255
+
+
+def read_pbm(path):
+    """Returns the image as rows of booleans, True for black"""
+    with open(path, "rb") as pbm:
+        data = pbm.read()
+
+    # Header is the magic, width and height separated by whitespace, with # comments anywhere
+    tokens = []
+    pos = 0
+    while len(tokens) < 3:
+        while data[pos : pos + 1].isspace():
+            pos += 1
+        if data[pos : pos + 1] == b"#":
+            pos = data.index(b"\n", pos)
+            continue
+        start = pos
+        while not data[pos : pos + 1].isspace():
+            pos += 1
+        tokens.append(data[start:pos].decode())
+    magic, width, height = tokens[0], int(tokens[1]), int(tokens[2])
+
+    if magic == "P1":
+        bits = [c == ord("1") for c in data[pos:] if c in b"01"]
+        return [bits[y * width : (y + 1) * width] for y in range(height)]
+    if magic == "P4":
+        raster = data[pos + 1 :]
+        stride = (width + 7) // 8
+        return [
+            [raster[y * stride + x // 8] & (0x80 >> (x % 8)) != 0 for x in range(width)]
+            for y in range(height)
+        ]
+    sys.exit(f"{path}: only P1 and P4 PBM files are supported")
+
+
+def paeth(a, b, c):
+    p = a + b - c
+    pa, pb, pc = abs(p - a), abs(p - b), abs(p - c)
+    if pa <= pb and pa <= pc:
+        return a
+    return b if pb <= pc else c
+
+
+def read_png(path, invert=False):
+    """Returns the image as rows of booleans, True for dark and opaque (light and opaque with `invert`)"""
+    with open(path, "rb") as png:
+        data = png.read()
+    if data[:8] != b"\x89PNG\r\n\x1a\n":
+        sys.exit(f"{path}: not a PNG")
+
+    chunks = {}
+    idat = b""
+    pos = 8
+    while pos < len(data):
+        length, kind = struct.unpack(">I4s", data[pos : pos + 8])
+        body = data[pos + 8 : pos + 8 + length]
+        if kind == b"IDAT":
+            idat += body
+        else:
+            chunks[kind] = body
+        pos += 12 + length
+
+    width, height, depth, color, _, _, interlace = struct.unpack(">IIBBBBB", chunks[b"IHDR"])
+    if interlace:
+        sys.exit(f"{path}: interlaced PNGs aren't supported")
+    channels = {0: 1, 2: 3, 3: 1, 4: 2, 6: 4}[color]
+    bits_per_pixel = channels * depth
+    stride = (width * bits_per_pixel + 7) // 8
+    step = max(1, bits_per_pixel // 8)  # Bytes back to the same channel of the previous pixel
+
+    # Undo the per row filters
+    raw = zlib.decompress(idat)
+    rows = []
+    prev = bytearray(stride)
+    for y in range(height):
+        kind = raw[y * (stride + 1)]
+        row = bytearray(raw[y * (stride + 1) + 1 : (y + 1) * (stride + 1)])
+        for i in range(stride):
+            a = row[i - step] if i >= step else 0
+            b = prev[i]
+            c = prev[i - step] if i >= step else 0
+            row[i] = (row[i] + [0, a, b, (a + b) // 2, paeth(a, b, c)][kind]) & 0xFF
+        rows.append(row)
+        prev = row
+
+    palette = chunks.get(b"PLTE", b"")
+    trns = chunks.get(b"tRNS", b"")
+    full = (1 << depth) - 1
+
+    def samples(row):
+        """Every channel value of the row, scaled to 0.0..1.0"""
+        if depth == 16:
+            return [struct.unpack(">H", row[i : i + 2])[0] / 0xFFFF for i in range(0, len(row), 2)]
+        per_byte = 8 // depth
+        return [
+            (row[i // per_byte] >> (8 - depth * (i % per_byte + 1)) & full) / full
+            for i in range(width * channels)
+        ]
+
+    image = []
+    for row in rows:
+        values = samples(row)
+        out = []
+        for x in range(width):
+            pixel = values[x * channels : (x + 1) * channels]
+            alpha = 1.0
+            if color == 3:
+                index = round(pixel[0] * full)
+                r, g, b = palette[index * 3 : index * 3 + 3]
+                gray = (0.299 * r + 0.587 * g + 0.114 * b) / 255
+                alpha = trns[index] / 255 if index < len(trns) else 1.0
+            elif color in (2, 6):
+                gray = 0.299 * pixel[0] + 0.587 * pixel[1] + 0.114 * pixel[2]
+            else:
+                gray = pixel[0]
+            if color in (4, 6):
+                alpha = pixel[-1]
+            # Inverting only swaps which opaque pixels are lit, transparent ones stay off
+            out.append(alpha >= 0.5 and (gray >= 0.5 if invert else gray < 0.5))
+        image.append(out)
+    return image
+
+
+def to_pages(image):
+    """Column bytes (LSB on top) for every 8 rows, one page after the other"""
+    height = len(image)
+    width = len(image[0]) if image else 0
+    data = []
+    for page in range((height + 7) // 8):
+        for x in range(width):
+            byte = 0
+            for bit in range(8):
+                y = page * 8 + bit
+                if y < height and image[y][x]:
+                    byte |= 1 << bit
+            data.append(byte)
+    return width, height, data
+
+
+def bitmap_name(path):
+    stem = os.path.splitext(os.path.basename(path))[0]
+    name = "".join(c if c.isalnum() else "_" for c in stem).upper()
+    if not name or name[0].isdigit():
+        name = "BITMAP_" + name
+    return name
+
+
+def render(bitmaps):
+    out = [
+        "//Generated by tools/img2bitmap.py, do not edit by hand",
+        "//Regenerate with: python3 tools/img2bitmap.py "
+        + " ".join(path for path, _, _, _, _ in bitmaps)
+        + " > src/bitmaps.rs",
+        "use crate::display::Bitmap;",
+    ]
+    for path, name, width, height, data in bitmaps:
+        out.append("")
+        out.append(f"//{os.path.basename(path)}, {width}x{height}")
+        out.append("#[rustfmt::skip]")
+        out.append('#[link_section = ".progmem.data"]')
+        out.append(f"static {name}_DATA: [u8; {len(data)}] = [")
+        for i in range(0, len(data), 16):
+            out.append("    " + " ".join(f"0x{b:02X}," for b in data[i : i + 16]))
+        out.append("];")
+        out.append(f"//SAFETY: {name}_DATA is in progmem and is exactly {width}x{height} pixels worth")
+        out.append(f"pub static {name}: Bitmap =")
+        out.append(
+            f"    unsafe {{ Bitmap::from_progmem({width}, {height}, core::ptr::addr_of!({name}_DATA).cast()) }};"
+        )
+    return "\n".join(out) + "\n"
+
+
+def main():
+    parser = argparse.ArgumentParser(description=__doc__.splitlines()[0])
+    parser.add_argument("images", nargs="+", help="PNG or PBM files to convert")
+    parser.add_argument(
+        "--invert",
+        action="store_true",
+        help="light up the light pixels instead of the dark ones",
+    )
+    args = parser.parse_args()
+
+    bitmaps = []
+    for path in args.images:
+        with open(path, "rb") as image:
+            is_png = image.read(8) == b"\x89PNG\r\n\x1a\n"
+        if is_png:
+            image = read_png(path, args.invert)
+        else:
+            image = read_pbm(path)
+            if args.invert:
+                image = [[not pixel for pixel in row] for row in image]
+        width, height, data = to_pages(image)
+        if width > MAX_SIZE or height > MAX_SIZE:
+            sys.exit(f"{path}: {width}x{height} is too big (max {MAX_SIZE}x{MAX_SIZE})")
+        bitmaps.append((path, bitmap_name(path), width, height, data))
+
+    sys.stdout.write(render(bitmaps))
+
+
+if __name__ == "__main__":
+    main()
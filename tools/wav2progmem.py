@@ -0,0 +1,126 @@
+#!/usr/bin/env python3
+"""Converts WAV files into 8-bit PCM clips stored in flash, for `src/pcm.rs`.
+
+Each input file becomes a `pub static <STEM>: Clip` in the generated module, so
+`assets/quiet_please.wav` turns into `clips::QUIET_PLEASE`. Input can be 8/16-bit PCM,
+mono or stereo (downmixed), at any sample rate (resampled to what the firmware plays at).
+
+Usage: python3 tools/wav2progmem.py assets/quiet_please.wav > src/clips.rs
+"""
+
+import argparse
+import os
+import sys
+import wave
+
+# 16MHz / 256 (8-bit fast PWM) / 8 overflows per sample, see src/pcm.rs
+SAMPLE_RATE = 7812
+# Clip length is a u16 on the firmware side, and a full 32KB of flash is less than that anyways
+MAX_SAMPLES = 0xFFFF
+
+
+def read_wav(path):
+    """Returns the samples in `path` as floats in -1.0..1.0 along with the sample rate"""
+    with wave.open(path, "rb") as wav:
+        if wav.getcomptype() != "NONE":
+            sys.exit(f"{path}: only uncompressed PCM is supported")
+        width = wav.getsampwidth()
+        channels = wav.getnchannels()
+        rate = wav.getframerate()
+        frames = wav.readframes(wav.getnframes())
+
+    if width == 1:
+        values = [(b - 128) / 128 for b in frames]
+    elif width == 2:
+        values = [
+            int.from_bytes(frames[i : i + 2], "little", signed=True) / 32768
+            for i in range(0, len(frames), 2)
+        ]
+    else:
+        sys.exit(f"{path}: only 8 and 16-bit samples are supported")
+
+    # Downmix by averaging the channels of each frame
+    mono = [
+        sum(values[i : i + channels]) / channels
+        for i in range(0, len(values), channels)
+    ]
+    return mono, rate
+
+
+def resample(samples, rate):
+    """Linear interpolation, good enough for a piezo buzzer"""
+    if rate == SAMPLE_RATE or not samples:
+        return samples
+    count = int(len(samples) * SAMPLE_RATE / rate)
+    out = []
+    for i in range(count):
+        pos = i * rate / SAMPLE_RATE
+        idx = int(pos)
+        frac = pos - idx
+        nxt = samples[min(idx + 1, len(samples) - 1)]
+        out.append(samples[idx] * (1 - frac) + nxt * frac)
+    return out
+
+
+def to_u8(samples, normalize):
+    peak = max((abs(s) for s in samples), default=0)
+    gain = 1 / peak if normalize and peak > 0 else 1
+    return [max(0, min(255, round(128 + s * gain * 127))) for s in samples]
+
+
+def clip_name(path):
+    stem = os.path.splitext(os.path.basename(path))[0]
+    name = "".join(c if c.isalnum() else "_" for c in stem).upper()
+    if not name or name[0].isdigit():
+        name = "CLIP_" + name
+    return name
+
+
+def render(clips):
+    out = [
+        "//Generated by tools/wav2progmem.py, do not edit by hand",
+        "//Regenerate with: python3 tools/wav2progmem.py "
+        + " ".join(path for path, _, _ in clips)
+        + " > src/clips.rs",
+        "use crate::pcm::Clip;",
+    ]
+    for path, name, data in clips:
+        out.append("")
+        out.append(f"//{os.path.basename(path)}, {len(data) / SAMPLE_RATE:.2f}s")
+        out.append("#[rustfmt::skip]")
+        out.append('#[link_section = ".progmem.data"]')
+        out.append(f"static {name}_SAMPLES: [u8; {len(data)}] = [")
+        for i in range(0, len(data), 16):
+            out.append("    " + " ".join(f"0x{b:02X}," for b in data[i : i + 16]))
+        out.append("];")
+        out.append(f"//SAFETY: {name}_SAMPLES is in progmem and has exactly that many samples")
+        out.append(f"pub static {name}: Clip =")
+        out.append(
+            f"    unsafe {{ Clip::from_progmem(core::ptr::addr_of!({name}_SAMPLES).cast(), {len(data)}) }};"
+        )
+    return "\n".join(out) + "\n"
+
+
+def main():
+    parser = argparse.ArgumentParser(description=__doc__.splitlines()[0])
+    parser.add_argument("wavs", nargs="+", help="WAV files to convert")
+    parser.add_argument(
+        "--no-normalize",
+        action="store_true",
+        help="keep the original levels instead of scaling the loudest sample to full range",
+    )
+    args = parser.parse_args()
+
+    clips = []
+    for path in args.wavs:
+        samples, rate = read_wav(path)
+        data = to_u8(resample(samples, rate), not args.no_normalize)
+        if len(data) > MAX_SAMPLES:
+            sys.exit(f"{path}: {len(data)} samples is too long (max {MAX_SAMPLES})")
+        clips.append((path, clip_name(path), data))
+
+    sys.stdout.write(render(clips))
+
+
+if __name__ == "__main__":
+    main()